@@ -6,7 +6,7 @@ import subprocess
 from doctest import master
 from zoneinfo import ZoneInfo
 
-from util.lib import compute_done, YD
+from util.lib import compute_done, parse_events, YD
 
 Deps = dict[YD, set[YD]]
 
@@ -189,7 +189,7 @@ params = dict(
 
 year_filename = f"./src/{yyear}.rs"
 module_filename = f"./src/{yyear}/{name}_{zday}.rs"
-binary_filename = f"./src/bin/{name}.rs"
+registry_filename = "./src/registry.rs"
 
 with open(year_filename, "a", encoding="utf-8") as f:
     f.write(f"pub mod {name}_{zday};\n")
@@ -234,26 +234,21 @@ mod test {
         )
     )
 
-with open(binary_filename, "w", encoding="utf-8") as f:
-    f.write(
-        Template(
-            """\
-use aoc::$yyear::${name}_$zday::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input($year, $day, do_solve)
-}
-"""
-        ).substitute(params)
-    )
+REGISTRY_MARKER = "    // new_day.py adds days above this line\n"
+with open(registry_filename, "r", encoding="utf-8") as f:
+    registry = f.read()
+registry = registry.replace(
+    REGISTRY_MARKER,
+    f"    {year} {day:>2} {yyear}::{name}_{zday};\n{REGISTRY_MARKER}",
+)
+with open(registry_filename, "w", encoding="utf-8") as f:
+    f.write(registry)
 
 subprocess.run(["cargo", "fmt"], check=True)
-subprocess.run(["cargo", "run", "--bin", name], check=True)
-subprocess.run(["git", "add", module_filename, binary_filename], check=True)
+subprocess.run(["cargo", "run", "--bin", "aoc", str(year), str(day)], check=True)
+subprocess.run(["git", "add", module_filename], check=True)
 day_spec = f"day {day}" if year == AOC_NOW.year else f"{year} day {day}"
 subprocess.run(
     ["git", "commit", "-am", f"skeleton for {day_spec}: {puzzle.title}"], check=True
 )
-subprocess.run(["idea", binary_filename])
 subprocess.run(["idea", module_filename])
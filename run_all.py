@@ -18,6 +18,7 @@ from lib import (
     FAINT,
     GREEN,
     LIGHT_RED,
+    parse_events,
     puzzle_name,
     RED,
 )
@@ -96,7 +97,7 @@ print(f"{row:>{WIDTH}}")
 prev = None
 env = {}
 env.update(os.environ)
-env["BEB_SOLVE_NANOS"] = "1"
+env["BEB_JSON_EVENTS"] = "1"
 exit_code = 0
 total_nanos = 0
 for y, d in reversed(sorted(to_run)):
@@ -111,6 +112,9 @@ for y, d in reversed(sorted(to_run)):
     for provider, tkn in TOKENS.items():
         print(f" {FAINT}|{END} ", end="", flush=True)
         env["AOC_SESSION"] = tkn
+        # inputs differ between accounts, so each needs its own cache
+        env["BEB_INPUT_DIR"] = os.path.join(os.getcwd(), "inputs", provider)
+        env["BEB_ACCOUNT"] = provider
         res = subprocess.run(
             [
                 "cargo",
@@ -134,9 +138,9 @@ for y, d in reversed(sorted(to_run)):
             mark = colored(f"{'✖':^{W_ACCOUNT}}", Colors.RED)
         print(f"{mark}", end="", flush=True)
         if res.returncode == 0:
-            for line in res.stdout.splitlines():
-                if line.startswith("¡¡solve nanos ") and line.endswith("!!"):
-                    solve_nanos.append(int(line[14:-2]))
+            for event in parse_events(res.stdout):
+                if event["kind"] == "join":
+                    solve_nanos.append(event["nanos"])
                     break
         else:
             print(res.stdout)
@@ -0,0 +1,227 @@
+//! A local store of known-correct answers, so solvers can be verified without
+//! talking to adventofcode.com. The store is a JSON file, keyed by year, day,
+//! and part:
+//!
+//! ```json
+//! {
+//!   "2015": { "1": { "a": "280", "b": "1797" } },
+//!   "accounts": {
+//!     "gmail": { "2015": { "1": { "a": "232", "b": "1783" } } }
+//!   }
+//! }
+//! ```
+//!
+//! When the `BEB_ACCOUNT` environment variable is set, only that account's
+//! answers (under `accounts`) are consulted, since each account has its own
+//! inputs. Otherwise the top-level years are used.
+use crate::aocd;
+use serde_json::{Map, Value};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the answers file. If unset, `answers.json` at
+/// the root of the crate is used.
+pub const ANSWERS_FILE_VAR: &str = "BEB_ANSWERS_FILE";
+
+/// Environment variable naming the account whose answers to use.
+pub const ACCOUNT_VAR: &str = "BEB_ACCOUNT";
+
+/// Environment variable controlling whether answers not in the store are
+/// submitted to adventofcode.com. Set to `0` to never submit.
+pub const SUBMIT_VAR: &str = "BEB_SUBMIT";
+
+const ACCOUNTS: &str = "accounts";
+
+/// The result of checking an answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// Incorrect, along with the expected answer, if known.
+    Wrong(Option<String>),
+    /// Neither the store nor adventofcode.com was consulted.
+    Unverified,
+}
+
+impl Verdict {
+    /// Whether this verdict should fail a run. Unverified answers don't.
+    pub fn is_ok(&self) -> bool {
+        !matches!(self, Verdict::Wrong(_))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong(_) => "wrong",
+            Verdict::Unverified => "unverified",
+        }
+    }
+}
+
+/// Whether answers not in the store get submitted upstream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Only consult the store; unknown answers are [Verdict::Unverified].
+    Local,
+    /// Submit unknown answers via `aocd`, and record correct ones.
+    Submit,
+}
+
+impl Mode {
+    pub fn from_env() -> Mode {
+        match std::env::var(SUBMIT_VAR) {
+            Ok(v) if v == "0" => Mode::Local,
+            _ => Mode::Submit,
+        }
+    }
+}
+
+/// The answers file, loaded into memory.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    account: Option<String>,
+    root: Map<String, Value>,
+}
+
+impl Answers {
+    /// Loads the answers file named by the environment, or the default. A
+    /// missing file is treated as empty.
+    pub fn load() -> io::Result<Answers> {
+        Answers::load_from(
+            aocd::env_path(ANSWERS_FILE_VAR, "answers.json"),
+            std::env::var(ACCOUNT_VAR).ok(),
+        )
+    }
+
+    pub fn load_from<P: AsRef<Path>>(path: P, account: Option<String>) -> io::Result<Answers> {
+        let path = path.as_ref().to_path_buf();
+        let root = match fs::read_to_string(&path) {
+            Ok(json) => match serde_json::from_str(&json)? {
+                Value::Object(m) => m,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{} isn't a JSON object", path.display()),
+                    ))
+                }
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Map::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Answers {
+            path,
+            account,
+            root,
+        })
+    }
+
+    /// The known answer for the given year/day/part, if any.
+    pub fn get(&self, year: u32, day: u8, part: &str) -> Option<&str> {
+        self.years()?
+            .get(&year.to_string())?
+            .get(day.to_string())?
+            .get(part)?
+            .as_str()
+    }
+
+    /// Checks `val` against the known answer, without consulting anyone else.
+    pub fn check(&self, year: u32, day: u8, part: &str, val: &str) -> Verdict {
+        match self.get(year, day, part) {
+            Some(ans) if ans == val => Verdict::Correct,
+            Some(ans) => Verdict::Wrong(Some(ans.to_string())),
+            None => Verdict::Unverified,
+        }
+    }
+
+    /// Records a known-correct answer, and writes the file back out.
+    pub fn record(&mut self, year: u32, day: u8, part: &str, val: &str) -> io::Result<()> {
+        let mut map = &mut self.root;
+        if let Some(acct) = &self.account {
+            map = object_entry(object_entry(map, ACCOUNTS), acct);
+        }
+        let map = object_entry(object_entry(map, &year.to_string()), &day.to_string());
+        map.insert(part.to_string(), Value::String(val.to_string()));
+        let mut json = serde_json::to_string_pretty(&self.root)?;
+        json.push('\n');
+        fs::write(&self.path, json)
+    }
+
+    fn years(&self) -> Option<&Map<String, Value>> {
+        match &self.account {
+            Some(acct) => self.root.get(ACCOUNTS)?.get(acct)?.as_object(),
+            None => Some(&self.root),
+        }
+    }
+}
+
+/// Returns the object under `key`, replacing whatever is there if it isn't an
+/// object already.
+fn object_entry<'a>(map: &'a mut Map<String, Value>, key: &str) -> &'a mut Map<String, Value> {
+    let v = map.entry(key).or_insert_with(|| Value::Object(Map::new()));
+    if !v.is_object() {
+        *v = Value::Object(Map::new());
+    }
+    v.as_object_mut().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    fn scratch_file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-answers-{name}-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let answers = Answers::load_from(scratch_file("missing"), None).unwrap();
+        assert_eq!(Verdict::Unverified, answers.check(2015, 1, "a", "280"));
+    }
+
+    #[test]
+    fn check() {
+        let path = scratch_file("check");
+        fs::write(&path, r#"{"2015": {"1": {"a": "280"}}}"#).unwrap();
+        let answers = Answers::load_from(&path, None).unwrap();
+        assert_eq!(Verdict::Correct, answers.check(2015, 1, "a", "280"));
+        assert_eq!(
+            Verdict::Wrong(Some("280".to_string())),
+            answers.check(2015, 1, "a", "281")
+        );
+        assert_eq!(Verdict::Unverified, answers.check(2015, 1, "b", "1797"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn accounts() {
+        let path = scratch_file("accounts");
+        fs::write(
+            &path,
+            r#"{"2015": {"1": {"a": "280"}}, "accounts": {"gmail": {"2015": {"1": {"a": "232"}}}}}"#,
+        )
+        .unwrap();
+        let answers = Answers::load_from(&path, Some("gmail".to_string())).unwrap();
+        assert_eq!(Some("232"), answers.get(2015, 1, "a"));
+        let answers = Answers::load_from(&path, Some("github".to_string())).unwrap();
+        assert_eq!(None, answers.get(2015, 1, "a"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn record() {
+        let path = scratch_file("record");
+        let mut answers = Answers::load_from(&path, Some("gmail".to_string())).unwrap();
+        answers.record(2015, 1, "a", "232").unwrap();
+        answers.record(2015, 1, "b", "1783").unwrap();
+        let answers = Answers::load_from(&path, Some("gmail".to_string())).unwrap();
+        assert_eq!(Verdict::Correct, answers.check(2015, 1, "a", "232"));
+        assert_eq!(Verdict::Correct, answers.check(2015, 1, "b", "1783"));
+        let answers = Answers::load_from(&path, None).unwrap();
+        assert_eq!(Verdict::Unverified, answers.check(2015, 1, "a", "232"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,54 +1,280 @@
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
 use std::io::{self, Write};
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::string::FromUtf8Error;
 
-/// Returns a `Result` with the given year/day pair's input as a `String`.
-pub fn get_input(year: u32, day: u8) -> io::Result<String> {
-    let output = Command::new("aocd")
-        .arg(year.to_string())
-        .arg(day.to_string())
-        .output()
-        .expect("Failed to execute 'aocd'");
+/// The ways talking to `aocd` can go wrong.
+#[derive(Debug)]
+pub enum AocdError {
+    /// The named tool couldn't be launched, most likely because it isn't on
+    /// the `PATH`.
+    ToolMissing(&'static str, io::Error),
+    /// The tool ran, but exited non-zero (or was killed, with no exit code).
+    Failed { code: Option<i32>, stderr: String },
+    /// The tool's output wasn't valid UTF-8.
+    InvalidUtf8(FromUtf8Error),
+    /// The AoC session token has expired, and needs to be replaced.
+    ExpiredToken,
+    /// The answer was wrong. If it had been previously answered, the expected
+    /// answer is included.
+    WrongAnswer { expected: Option<String> },
+    /// An answer was submitted too recently to submit another.
+    RateLimited,
+    /// Reading or writing local files failed.
+    Io(io::Error),
+}
+
+impl Display for AocdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocdError::ToolMissing(tool, e) => write!(f, "Failed to execute '{tool}': {e}"),
+            AocdError::Failed {
+                code: Some(c),
+                stderr,
+            } => write!(f, "Exited with {c}: {}", stderr.trim_end()),
+            AocdError::Failed { code: None, stderr } => {
+                write!(f, "Terminated: {}", stderr.trim_end())
+            }
+            AocdError::InvalidUtf8(e) => write!(f, "aocd output contains invalid UTF-8: {e}"),
+            AocdError::ExpiredToken => write!(f, "AoC token has expired; go update it"),
+            AocdError::WrongAnswer {
+                expected: Some(ans),
+            } => write!(f, "Wrong answer, expected '{ans}'"),
+            AocdError::WrongAnswer { expected: None } => write!(f, "Wrong answer"),
+            AocdError::RateLimited => write!(f, "Answer submitted too recently; wait a bit"),
+            AocdError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for AocdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocdError::ToolMissing(_, e) | AocdError::Io(e) => Some(e),
+            AocdError::InvalidUtf8(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocdError {
+    fn from(e: io::Error) -> Self {
+        AocdError::Io(e)
+    }
+}
+
+impl From<AocdError> for io::Error {
+    fn from(e: AocdError) -> Self {
+        let kind = match &e {
+            AocdError::ToolMissing(_, e) | AocdError::Io(e) => e.kind(),
+            AocdError::InvalidUtf8(_) => io::ErrorKind::InvalidData,
+            AocdError::ExpiredToken => io::ErrorKind::PermissionDenied,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, AocdError>;
+
+/// Environment variable naming the directory inputs are cached in. If unset,
+/// an `inputs` directory at the root of the crate is used.
+pub const INPUT_DIR_VAR: &str = "BEB_INPUT_DIR";
+
+/// Returns the directory inputs are cached in, laid out as `{year}/{day}.txt`.
+pub fn input_dir() -> PathBuf {
+    env_path(INPUT_DIR_VAR, "inputs")
+}
+
+/// Returns the path the given year/day pair's input is cached at, whether or
+/// not it exists.
+pub fn input_path(year: u32, day: u8) -> PathBuf {
+    input_path_in(&input_dir(), year, day)
+}
+
+fn input_path_in(dir: &Path, year: u32, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{day}.txt"))
+}
+
+/// Returns the value of the named environment variable as a path, or the crate
+/// root joined with `default` if it isn't set.
+pub(crate) fn env_path(var: &str, default: &str) -> PathBuf {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(default))
+}
+
+/// Returns a `Result` with the given year/day pair's input as a `String`. The
+/// [input cache](input_dir) is checked first, and only if the input isn't
+/// there is `aocd` invoked, with its result written to the cache for next time.
+pub fn get_input(year: u32, day: u8) -> Result<String> {
+    get_input_in(&input_dir(), year, day, fetch_input)
+}
+
+fn get_input_in<F>(dir: &Path, year: u32, day: u8, fetch: F) -> Result<String>
+where
+    F: FnOnce(u32, u8) -> Result<String>,
+{
+    let path = input_path_in(dir, year, day);
+    match fs::read_to_string(&path) {
+        Ok(input) => return Ok(input),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+    let input = fetch(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, &input)?;
+    Ok(input)
+}
+
+fn fetch_input(year: u32, day: u8) -> Result<String> {
+    let output = run("aocd", [year.to_string().into(), day.to_string().into()])?;
     if output.status.success() {
-        String::from_utf8(output.stdout).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::Other,
-                format!("Input contains invalid UTF-8: {e}"),
-            )
-        })
+        String::from_utf8(output.stdout).map_err(AocdError::InvalidUtf8)
     } else {
         io::stdout().write_all(&output.stdout)?;
         io::stderr().write_all(&output.stderr)?;
-        Err(io::Error::new(
-            io::ErrorKind::Other,
-            format!("aocd exited with {}", output.status),
-        ))
+        Err(failure(output))
+    }
+}
+
+/// Runs the given program to completion, capturing its output.
+fn run<I>(program: &'static str, args: I) -> Result<Output>
+where
+    I: IntoIterator<Item = OsString>,
+{
+    Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| AocdError::ToolMissing(program, e))
+}
+
+/// Interprets a non-zero exit which isn't otherwise accounted for. `aocd`
+/// doesn't handle expired tokens itself, so sniff for the 400 it gets back.
+fn failure(output: Output) -> AocdError {
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    if stderr.contains("DeadTokenError") || stderr.contains("HTTP 400") {
+        AocdError::ExpiredToken
+    } else {
+        AocdError::Failed {
+            code: output.status.code(),
+            stderr,
+        }
     }
 }
 
 const SUBMIT_WRAPPER: &'static str = include_str!("aocd_submit_wrapper.py");
 
-pub(crate) fn submit_answer(year: u32, day: u8, part: &str, val: &str) -> io::Result<bool> {
+/// Submits the given answer, or checks it against the previously accepted
+/// answer, if there is one. Exit codes are defined by the wrapper script.
+pub(crate) fn submit_answer(year: u32, day: u8, part: &str, val: &str) -> Result<()> {
     if part != "a" && part != "b" {
-        return Err(io::Error::new(
-            io::ErrorKind::Other,
+        return Err(AocdError::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
             "Only parts 'a' and 'b' can be submitted",
-        ));
+        )));
     }
     // python src/aocd_submit_wrapper.py 2015 1 a 280
-    let output = Command::new("python")
-        .arg("-c")
-        .arg(SUBMIT_WRAPPER)
-        .arg(year.to_string())
-        .arg(day.to_string())
-        .arg(part)
-        .arg(val.to_string())
-        .output()
-        .expect("Failed to execute 'aocd'");
-    if output.status.success() {
-        Ok(true)
-    } else {
-        io::stdout().write_all(&output.stdout)?;
-        io::stderr().write_all(&output.stderr)?;
-        Ok(false)
+    let output = run(
+        "python",
+        [
+            "-c".into(),
+            SUBMIT_WRAPPER.into(),
+            year.to_string().into(),
+            day.to_string().into(),
+            part.into(),
+            val.into(),
+        ],
+    )?;
+    io::stdout().write_all(&output.stdout)?;
+    match output.status.code() {
+        Some(0) => Ok(()),
+        Some(2) => Err(AocdError::WrongAnswer {
+            expected: Some(String::from_utf8(output.stderr).map_err(AocdError::InvalidUtf8)?),
+        }),
+        Some(3) => Err(AocdError::WrongAnswer { expected: None }),
+        Some(4) => Err(AocdError::ExpiredToken),
+        Some(5) => Err(AocdError::RateLimited),
+        _ => {
+            io::stderr().write_all(&output.stderr)?;
+            Err(failure(output))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+    use std::process::ExitStatus;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-aocd-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn input_path_layout() {
+        assert_eq!(
+            Path::new("cache").join("2024").join("7.txt"),
+            input_path_in(Path::new("cache"), 2024, 7)
+        );
+    }
+
+    #[test]
+    fn reads_cached_input() {
+        let dir = scratch_dir("cached");
+        let path = input_path_in(&dir, 2015, 1);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "(()(\n").unwrap();
+        let input = get_input_in(&dir, 2015, 1, |_, _| panic!("Shouldn't fetch!")).unwrap();
+        assert_eq!("(()(\n", input);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn caches_fetched_input() {
+        let dir = scratch_dir("fetched");
+        let input = get_input_in(&dir, 2015, 1, |_, _| Ok("))(".to_string())).unwrap();
+        assert_eq!("))(", input);
+        assert_eq!(
+            "))(",
+            fs::read_to_string(input_path_in(&dir, 2015, 1)).unwrap()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reports_fetch_failure() {
+        let dir = scratch_dir("failed");
+        let e = get_input_in(&dir, 2015, 1, |_, _| Err(AocdError::ExpiredToken)).unwrap_err();
+        assert!(matches!(e, AocdError::ExpiredToken));
+        assert!(!dir.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn classifies_failures() {
+        use std::os::unix::process::ExitStatusExt;
+        let output = |stderr: &str| Output {
+            status: ExitStatus::from_raw(1 << 8),
+            stdout: Vec::new(),
+            stderr: stderr.as_bytes().to_vec(),
+        };
+        assert!(matches!(
+            failure(output("aocd.exceptions.AocdError: HTTP 400 at https://...")),
+            AocdError::ExpiredToken
+        ));
+        assert!(matches!(
+            failure(output("Traceback...")),
+            AocdError::Failed { code: Some(1), .. }
+        ));
     }
 }
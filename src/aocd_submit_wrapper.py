@@ -1,25 +1,38 @@
 import sys
 
+from aocd.exceptions import DeadTokenError
 from aocd.models import Puzzle
 from aocd.post import submit
 
+# These exit codes are interpreted by aocd.rs, so keep them in sync!
+CORRECT = 0
+WRONG_EXPECTED = 2  # expected answer is written to stderr
+WRONG = 3
+EXPIRED_TOKEN = 4
+RATE_LIMITED = 5
+
 [_, year, day, part, val] = sys.argv
 if part != "a" and part != "b":
     raise TypeError(f"Unknown '{part}' part")
-puzzle = Puzzle(year=int(year), day=int(day))
-if not getattr(puzzle, f"answered_{part}"):
-    # this is a little silly, but Puzzle itself doesn't offer a reopen flag
-    submit(val, part=part, day=puzzle.day, year=puzzle.year, reopen=False)
-    if getattr(puzzle, f"answered_{part}"):
-        exit(0)  # woo!
-    else:
-        exit(1)  # bummer
-answer = getattr(puzzle, f"answer_{part}")
+try:
+    puzzle = Puzzle(year=int(year), day=int(day))
+    if not getattr(puzzle, f"answered_{part}"):
+        # this is a little silly, but Puzzle itself doesn't offer a reopen flag
+        resp = submit(val, part=part, day=puzzle.day, year=puzzle.year, reopen=False)
+        if getattr(puzzle, f"answered_{part}"):
+            exit(CORRECT)  # woo!
+        if resp is not None and "answer too recently" in resp.data.decode():
+            exit(RATE_LIMITED)
+        exit(WRONG)  # bummer
+    answer = getattr(puzzle, f"answer_{part}")
+except DeadTokenError:
+    exit(EXPIRED_TOKEN)
 if val == answer:
     print(f"'{val}' is correct!")
-    exit(0)
+    exit(CORRECT)
 if val.__contains__("\n"):
     print(f"Expected '{answer}', actual:\n{val}")
 else:
     print(f"Expected '{answer}', actual '{val}'")
-exit(2)
+print(answer, file=sys.stderr, end="")
+exit(WRONG_EXPECTED)
@@ -0,0 +1,136 @@
+//! Running many solvers at once, on a pool of worker threads. Each solver is
+//! [collected](crate::driver::collect) in isolation, and then all of them are
+//! [reported](crate::driver::report) in the order they were passed, so output
+//! is deterministic regardless of which finished first.
+use crate::aocd::AocdError;
+use crate::driver::{self, Collected};
+use crate::registry::Solver;
+use crate::timing::profile;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// A solver to run, and the year/day whose input to run it on.
+#[derive(Clone, Copy, Debug)]
+pub struct Job {
+    pub year: u32,
+    pub day: u8,
+    pub solve: Solver,
+}
+
+/// How many workers [collect_all] is currently running, if any.
+static WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Collects every job, using up to `threads` workers, each limited to the
+/// `timeout`, and returns the results in the same order as `jobs`.
+pub fn collect_all(
+    jobs: &[Job],
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Vec<Result<Collected, AocdError>> {
+    WORKERS.store(threads, Ordering::SeqCst);
+    let results = in_pool(jobs, threads, |job| {
+        driver::collect(job.year, job.day, job.solve, timeout)
+    });
+    WORKERS.store(0, Ordering::SeqCst);
+    results
+}
+
+/// Applies `f` to every item, using up to `threads` workers, and returns the
+/// results in the same order as `items`.
+fn in_pool<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<_>> = Mutex::new(items.iter().map(|_| None).collect());
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let r = f(item);
+                results.lock().unwrap()[i] = Some(r);
+            });
+        }
+    });
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("Every item should have been processed"))
+        .collect()
+}
+
+/// Runs every job in parallel, each limited to the `timeout`, then prints and
+/// verifies each in order. The `before_each` callback is invoked before each
+/// job is reported, e.g., to print a header. Profiles are reported with each
+/// job, except spans from threads the solvers spawned themselves, which can't
+/// be told apart, so are reported together after every job.
+pub fn run_all<F>(
+    jobs: &[Job],
+    threads: usize,
+    timeout: Option<Duration>,
+    mut before_each: F,
+) -> Vec<io::Result<()>>
+where
+    F: FnMut(&Job),
+{
+    let results = collect_all(jobs, threads, timeout)
+        .into_iter()
+        .zip(jobs)
+        .map(|(collected, job)| {
+            before_each(job);
+            driver::report(job.year, job.day, collected)
+        })
+        .collect();
+    driver::report_profile(&profile::take());
+    results
+}
+
+/// The number of workers to use by default: one per available core.
+pub fn default_threads() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// The number of threads a solver should spread its own work across: just its
+/// own if [collect_all] is running it alongside others, which are already
+/// keeping the cores busy, or else one per available core.
+pub fn solver_threads() -> usize {
+    if WORKERS.load(Ordering::SeqCst) > 1 {
+        1
+    } else {
+        default_threads()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+        // later items finish first
+        let results = in_pool(&items, 4, |&n| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * 2
+        });
+        assert_eq!(items.iter().map(|n| n * 2).collect::<Vec<_>>(), results);
+    }
+
+    #[test]
+    fn more_threads_than_items() {
+        assert_eq!(vec![1, 2], in_pool(&[0, 1], 16, |n| n + 1));
+        assert_eq!(Vec::<i32>::new(), in_pool(&[], 16, |n: &i32| n + 1));
+    }
+}
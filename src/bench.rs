@@ -0,0 +1,254 @@
+//! Repeated timing of solvers, with summary statistics, and comparison against
+//! a saved baseline. Each [Part] a solver sends is timed separately (as the
+//! time since the previous one), along with the solver's total time.
+use crate::aocd;
+use crate::registry::Solver;
+use crate::Part;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Environment variable naming the baseline file. If unset, `bench.json` at
+/// the root of the crate is used.
+pub const BASELINE_FILE_VAR: &str = "BEB_BENCH_BASELINE";
+
+/// The label for a solver's total time.
+pub const TOTAL: &str = "total";
+
+/// Summary statistics for a set of timings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Computes stats for the passed (non-empty) samples, using nearest-rank
+    /// percentiles.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Can't compute stats without samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let rank = |p: usize| sorted[(p * sorted.len()).div_ceil(100).max(1) - 1];
+        Stats {
+            min: sorted[0],
+            median: rank(50),
+            p95: rank(95),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Runs `solve` once on `input`, returning each part's label and duration, in
+/// the order they were sent, followed by the [TOTAL]. [Part::Progress] updates
+/// are ignored.
+pub fn time_parts(solve: Solver, input: &str) -> Vec<(String, Duration)> {
+    let (tx, rx) = channel();
+    let listener = thread::spawn(move || {
+        rx.into_iter()
+            .map(|p| (p, Instant::now()))
+            .collect::<Vec<_>>()
+    });
+    let start = Instant::now();
+    solve(input, tx);
+    let end = Instant::now();
+    let mut prev = start;
+    let mut others = 0;
+    let mut result: Vec<_> = listener
+        .join()
+        .expect("Listener thread should have exited cleanly")
+        .into_iter()
+        .filter(|(p, _)| !matches!(p, Part::Progress { .. }))
+        .map(|(p, at)| {
+            let lbl = match p {
+                Part::Other(_) => {
+                    others += 1;
+                    format!("other {others}")
+                }
+                p => p.kind().to_string(),
+            };
+            // the listener may wake after the solver returns
+            let at = at.min(end);
+            let d = at - prev;
+            prev = at;
+            (lbl, d)
+        })
+        .collect();
+    result.push((TOTAL.to_string(), end - start));
+    result
+}
+
+/// Runs `solve` on `input` `warmup` times, discarding the results, then
+/// `iterations` more times, returning stats for each part and the total, in
+/// the order the first iteration sent them.
+pub fn bench(solve: Solver, input: &str, warmup: usize, iterations: usize) -> Vec<(String, Stats)> {
+    for _ in 0..warmup {
+        time_parts(solve, input);
+    }
+    let mut order = Vec::new();
+    let mut samples: HashMap<String, Vec<Duration>> = HashMap::new();
+    for _ in 0..iterations.max(1) {
+        for (lbl, d) in time_parts(solve, input) {
+            let s = samples.entry(lbl.clone()).or_default();
+            if s.is_empty() && !order.contains(&lbl) {
+                order.push(lbl);
+            }
+            s.push(d);
+        }
+    }
+    order
+        .into_iter()
+        .map(|lbl| {
+            let stats = Stats::from_samples(&samples[&lbl]);
+            (lbl, stats)
+        })
+        .collect()
+}
+
+/// Saved median timings, keyed by solver name and part label.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    root: Map<String, Value>,
+}
+
+impl Baseline {
+    /// Loads the baseline file named by the environment, or the default. A
+    /// missing file is treated as empty.
+    pub fn load() -> io::Result<Baseline> {
+        Baseline::load_from(Baseline::path())
+    }
+
+    pub fn load_from<P: AsRef<Path>>(path: P) -> io::Result<Baseline> {
+        match fs::read_to_string(path) {
+            Ok(json) => match serde_json::from_str(&json)? {
+                Value::Object(root) => Ok(Baseline { root }),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Baseline isn't a JSON object",
+                )),
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn path() -> std::path::PathBuf {
+        aocd::env_path(BASELINE_FILE_VAR, "bench.json")
+    }
+
+    /// The baseline median for the given solver and part, if there is one.
+    pub fn get(&self, name: &str, part: &str) -> Option<Duration> {
+        self.root
+            .get(name)?
+            .get(part)?
+            .as_u64()
+            .map(Duration::from_nanos)
+    }
+
+    /// The percentage change from the baseline to `d`, if there's a baseline.
+    /// Positive is slower.
+    pub fn delta(&self, name: &str, part: &str, d: Duration) -> Option<f64> {
+        let base = self.get(name, part)?.as_nanos() as f64;
+        if base == 0.0 {
+            return None;
+        }
+        Some((d.as_nanos() as f64 - base) * 100.0 / base)
+    }
+
+    /// Replaces the given solver's baseline with the passed stats' medians.
+    pub fn set(&mut self, name: &str, stats: &[(String, Stats)]) {
+        let parts = stats
+            .iter()
+            .map(|(lbl, s)| (lbl.clone(), Value::from(s.median.as_nanos() as u64)))
+            .collect();
+        self.root.insert(name.to_string(), Value::Object(parts));
+    }
+
+    /// Writes the baseline to the file named by the environment, or the
+    /// default.
+    pub fn save(&self) -> io::Result<()> {
+        self.save_to(Baseline::path())
+    }
+
+    pub fn save_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut json = serde_json::to_string_pretty(&self.root)?;
+        json.push('\n');
+        fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::mpsc::Sender;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats() {
+        let samples: Vec<_> = (1..=20).rev().map(ms).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(ms(1), stats.min);
+        assert_eq!(ms(10), stats.median);
+        assert_eq!(ms(19), stats.p95);
+        assert_eq!(ms(20), stats.max);
+    }
+
+    #[test]
+    fn stats_of_one() {
+        let stats = Stats::from_samples(&[ms(3)]);
+        assert_eq!(ms(3), stats.min);
+        assert_eq!(ms(3), stats.median);
+        assert_eq!(ms(3), stats.p95);
+        assert_eq!(ms(3), stats.max);
+    }
+
+    fn solve(_: &str, tx: Sender<Part>) {
+        tx.send(Part::Parse()).unwrap();
+        tx.send(Part::A("1".to_string())).unwrap();
+        tx.send(Part::Progress {
+            done: 1,
+            total: 2,
+            label: "others".to_string(),
+        })
+        .unwrap();
+        tx.send(Part::Other("2".to_string())).unwrap();
+        tx.send(Part::Other("3".to_string())).unwrap();
+        tx.send(Part::B("4".to_string())).unwrap();
+    }
+
+    #[test]
+    fn labels() {
+        let labels: Vec<_> = bench(solve, "", 1, 3)
+            .into_iter()
+            .map(|(lbl, _)| lbl)
+            .collect();
+        assert_eq!(vec!["parse", "a", "other 1", "other 2", "b", TOTAL], labels);
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let mut baseline = Baseline::default();
+        let stats = Stats::from_samples(&[ms(200)]);
+        baseline.set("guard_gallivant_06", &[("b".to_string(), stats)]);
+        baseline.save_to(&path).unwrap();
+        let baseline = Baseline::load_from(&path).unwrap();
+        assert_eq!(Some(ms(200)), baseline.get("guard_gallivant_06", "b"));
+        assert_eq!(
+            Some(50.0),
+            baseline.delta("guard_gallivant_06", "b", ms(300))
+        );
+        assert_eq!(None, baseline.delta("guard_gallivant_06", "a", ms(300)));
+        fs::remove_file(&path).unwrap();
+    }
+}
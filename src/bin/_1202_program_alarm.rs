@@ -1,6 +0,0 @@
-use aoc::y2019::_1202_program_alarm_02::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2019, 2, do_solve)
-}
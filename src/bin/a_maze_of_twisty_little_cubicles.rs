@@ -1,7 +0,0 @@
-use aoc::with_input;
-use aoc::y2016::a_maze_of_twisty_little_cubicles_13::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    with_input(2016, 13, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2018::alchemical_reduction_05::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2018, 5, do_solve)
-}
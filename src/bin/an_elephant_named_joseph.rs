@@ -1,6 +0,0 @@
-use aoc::y2016::an_elephant_named_joseph_19::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2016, 19, do_solve)
-}
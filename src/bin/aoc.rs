@@ -0,0 +1,106 @@
+use aoc::batch::{self, Job};
+use aoc::driver;
+use aoc::registry::{self, Solution};
+use std::env;
+use std::io;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: aoc (all | <year> [<day>]) [--alt] [-j | --parallel [<threads>]]
+           [--timeout <seconds>]
+
+With --parallel, the selected solvers run concurrently (on one thread per core
+by default), and their answers are printed in order once they've all finished.
+With --timeout, any solver still running after that long is abandoned, so the
+rest can carry on.";
+
+fn main() -> ExitCode {
+    let mut alt = false;
+    let mut threads = None;
+    let mut timeout = driver::default_timeout();
+    let mut args = Vec::new();
+    let mut argv = env::args().skip(1).peekable();
+    while let Some(a) = argv.next() {
+        match a.as_str() {
+            "--alt" => alt = true,
+            "-j" | "--parallel" => {
+                threads = Some(
+                    argv.next_if(|n| n.parse::<usize>().is_ok())
+                        .map_or_else(batch::default_threads, |n| n.parse().unwrap()),
+                )
+            }
+            "--timeout" => match argv
+                .next_if(|s| s.parse::<f64>().is_ok())
+                .and_then(|s| Duration::try_from_secs_f64(s.parse().unwrap()).ok())
+            {
+                Some(secs) => timeout = Some(secs),
+                None => {
+                    eprintln!("Expected a number of seconds after '--timeout'\n{USAGE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ => args.push(a),
+        }
+    }
+    let solutions: Vec<&Solution> = match registry::select(&args) {
+        Ok(s) => s,
+        Err(msg) => {
+            eprintln!("{msg}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut selected = Vec::new();
+    for s in solutions {
+        let (name, solve) = if alt {
+            match s.alternate {
+                Some(a) => (a.name, a.solve),
+                None if args.len() == 2 => {
+                    eprintln!("{} day {} has no alternate solver", s.year, s.day);
+                    return ExitCode::FAILURE;
+                }
+                None => continue,
+            }
+        } else {
+            (s.name, s.solve)
+        };
+        let job = Job {
+            year: s.year,
+            day: s.day,
+            solve,
+        };
+        selected.push((name, job));
+    }
+    let header = |name: &str, job: &Job| println!("{:>4} {:>2} {name}", job.year, job.day);
+    // run lazily when sequential, so each failure is reported as it happens
+    let results: Box<dyn Iterator<Item = io::Result<()>>> = match threads {
+        Some(threads) => {
+            let jobs: Vec<_> = selected.iter().map(|(_, j)| *j).collect();
+            let mut names = selected.iter().map(|(n, _)| *n);
+            let results = batch::run_all(&jobs, threads, timeout, |job| {
+                header(names.next().unwrap(), job)
+            });
+            Box::new(results.into_iter())
+        }
+        None => Box::new(selected.iter().map(|(name, job)| {
+            header(name, job);
+            driver::with_input_timeout(job.year, job.day, job.solve, timeout)
+        })),
+    };
+    let mut failed = Vec::new();
+    for ((name, _), r) in selected.iter().zip(results) {
+        if let Err(e) = r {
+            eprintln!("{e}");
+            failed.push(*name);
+        }
+    }
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("Failed: {}", failed.join(", "));
+        ExitCode::FAILURE
+    }
+}
@@ -1,6 +0,0 @@
-use aoc::y2015::aunt_sue_16::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2015, 16, do_solve)
-}
@@ -0,0 +1,184 @@
+use aoc::aocd::get_input;
+use aoc::bench::{bench, Baseline, Stats, TOTAL};
+use aoc::registry::{self, Solution, Solver};
+use console::style;
+use std::env;
+use std::io::{stdout, Write};
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str =
+    "Usage: bench [all | <year> [<day>]] [--alt] [--warmup <n>] [--iterations <n>] [--save]
+
+Runs each selected solver warmup times (default 1), then iterations times
+(default 10), and reports min/median/p95/max for each part. Medians are
+compared against the saved baseline, which --save replaces.";
+
+struct Options {
+    alt: bool,
+    warmup: usize,
+    iterations: usize,
+    save: bool,
+    selection: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let opts = match parse_options(env::args().skip(1)) {
+        Ok(o) => o,
+        Err(msg) => {
+            eprintln!("{msg}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let solutions = if opts.selection.is_empty() {
+        let latest = registry::all().last().unwrap().year;
+        registry::for_year(latest).collect()
+    } else {
+        match registry::select(&opts.selection) {
+            Ok(s) => s,
+            Err(msg) => {
+                eprintln!("{msg}\n{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    let solvers: Vec<(&Solution, &str, Solver)> = solutions
+        .into_iter()
+        .filter_map(|s| {
+            if opts.alt {
+                s.alternate.map(|a| (s, a.name, a.solve))
+            } else {
+                Some((s, s.name, s.solve))
+            }
+        })
+        .collect();
+    if solvers.is_empty() {
+        eprintln!("Nothing to benchmark?!");
+        return ExitCode::FAILURE;
+    }
+
+    let inputs: Vec<_> = {
+        let mut lock = stdout().lock();
+        write!(lock, "Reading inputs").unwrap();
+        let inputs = solvers
+            .iter()
+            .map(|(s, _, _)| {
+                write!(lock, "{:.>4}", s.day).unwrap();
+                lock.flush().unwrap();
+                get_input(s.year, s.day)
+            })
+            .collect::<Result<_, _>>();
+        match inputs {
+            Ok(i) => i,
+            Err(e) => {
+                writeln!(lock).unwrap();
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    println!("...done!");
+
+    let mut baseline = match Baseline::load() {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("Failed to load baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut totals = Vec::new();
+    for ((s, name, solve), input) in solvers.iter().zip(&inputs) {
+        println!("{:>4} {:>2} {name}", s.year, s.day);
+        let stats = bench(
+            *solve,
+            input.trim_end_matches('\n'),
+            opts.warmup,
+            opts.iterations,
+        );
+        print_stats(&baseline, name, &stats);
+        let (_, total) = stats.iter().find(|(lbl, _)| lbl == TOTAL).unwrap();
+        totals.push((*name, total.median));
+        if opts.save {
+            baseline.set(name, &stats);
+        }
+    }
+    print_totals(&totals);
+    if opts.save {
+        if let Err(e) = baseline.save() {
+            eprintln!("Failed to save baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+        println!("Baseline saved.");
+    }
+    ExitCode::SUCCESS
+}
+
+fn parse_options<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let mut opts = Options {
+        alt: false,
+        warmup: 1,
+        iterations: 10,
+        save: false,
+        selection: Vec::new(),
+    };
+    while let Some(a) = args.next() {
+        let mut count = |flag: &str| {
+            args.next()
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(|| format!("Expected a count after '{flag}'"))
+        };
+        match a.as_str() {
+            "--alt" => opts.alt = true,
+            "--save" => opts.save = true,
+            "--warmup" => opts.warmup = count(&a)?,
+            "-n" | "--iterations" => opts.iterations = count(&a)?,
+            "-h" | "--help" => return Err(String::new()),
+            _ => opts.selection.push(a),
+        }
+    }
+    Ok(opts)
+}
+
+fn print_stats(baseline: &Baseline, name: &str, stats: &[(String, Stats)]) {
+    println!(
+        "    {:>8} | {:>12} | {:>12} | {:>12} | {:>12} |",
+        "part", "min", "median", "p95", "max"
+    );
+    for (lbl, s) in stats {
+        let delta = match baseline.delta(name, lbl, s.median) {
+            Some(pct) if pct > 10.0 => style(format!("{pct:+7.1}%")).red(),
+            Some(pct) if pct < -10.0 => style(format!("{pct:+7.1}%")).green(),
+            Some(pct) => style(format!("{pct:+7.1}%")).dim(),
+            None => style(String::new()),
+        };
+        println!(
+            "    {lbl:>8} | {:>12?} | {:>12?} | {:>12?} | {:>12?} | {delta}",
+            s.min, s.median, s.p95, s.max
+        );
+    }
+}
+
+fn print_totals(totals: &[(&str, Duration)]) {
+    let total = totals.iter().map(|(_, d)| d).sum::<Duration>();
+    let max = totals
+        .iter()
+        .map(|(_, d)| d)
+        .max()
+        .unwrap()
+        .as_nanos()
+        .max(1);
+    let len = totals.iter().map(|(l, _)| l.len()).max().unwrap();
+    let width = 100 - len;
+    println!();
+    for (n, (l, d)) in totals.iter().enumerate() {
+        println!(
+            "{:>3} {l:1$} | {d:>12?} | {2:3$} | {4:>5.1}%",
+            n + 1,
+            len,
+            "#".repeat((d.as_nanos() * width as u128 / max) as usize),
+            width,
+            d.as_nanos() as f64 * 100.0 / total.as_nanos().max(1) as f64
+        );
+    }
+    println!("    {:>1$} | {total:>12?}", "total", len);
+}
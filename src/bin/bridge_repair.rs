@@ -1,6 +0,0 @@
-use aoc::y2024::bridge_repair_07::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 7, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2022::calorie_counting_01::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2022, 1, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::ceres_search_04::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 4, do_solve)
-}
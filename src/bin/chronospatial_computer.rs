@@ -1,6 +0,0 @@
-use aoc::y2024::chronospatial_computer_17::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 17, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::claw_contraption_13::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 13, do_solve)
-}
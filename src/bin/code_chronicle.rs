@@ -1,6 +0,0 @@
-use aoc::y2024::code_chronicle_25::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 25, do_solve)
-}
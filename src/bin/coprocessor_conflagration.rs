@@ -1,6 +0,0 @@
-use aoc::y2017::coprocessor_conflagration_23::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2017, 23, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2020::crab_combat_22::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2020, 22, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::crossed_wires_24::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 24, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::disk_fragmenter_09::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 9, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2022::distress_signal_13::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2022, 13, do_solve)
-}
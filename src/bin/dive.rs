@@ -1,6 +0,0 @@
-use aoc::y2021::dive_02::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2021, 2, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::garden_groups_12b::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 12, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2023::gear_ratios_03::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2023, 3, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2022::grove_positioning_system_20::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2022, 20, do_solve)
-}
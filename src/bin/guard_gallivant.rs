@@ -1,6 +0,0 @@
-use aoc::y2024::guard_gallivant_06::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 6, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::historian_hysteria_01::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 1, do_solve)
-}
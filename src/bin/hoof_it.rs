@@ -1,6 +0,0 @@
-use aoc::y2024::hoof_it_10::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 10, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2017::inverse_captcha_01::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2017, 1, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::keypad_conundrum_21::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 21, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::lan_party_23::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 23, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2023::lens_library_15::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2023, 15, do_solve)
-}
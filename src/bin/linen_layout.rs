@@ -1,6 +0,0 @@
-use aoc::y2024::linen_layout_19::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 19, do_solve)
-}
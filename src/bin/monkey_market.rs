@@ -1,6 +0,0 @@
-use aoc::y2024::monkey_market_22::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 22, do_solve)
-}
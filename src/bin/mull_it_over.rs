@@ -1,6 +0,0 @@
-use aoc::y2024::mull_it_over_03::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 3, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2020::passport_processing_04::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2020, 4, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::plutonian_pebbles_11::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 11, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::print_queue_05::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 5, do_solve)
-}
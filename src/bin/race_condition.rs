@@ -1,6 +0,0 @@
-use aoc::y2024::race_condition_20::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 20, do_solve)
-}
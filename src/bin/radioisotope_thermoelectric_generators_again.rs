@@ -1,6 +0,0 @@
-use aoc::y2016::radioisotope_thermoelectric_generators_11_again::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2016, 11, do_solve)
-}
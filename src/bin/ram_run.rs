@@ -1,6 +0,0 @@
-use aoc::y2024::ram_run_18::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 18, do_solve)
-}
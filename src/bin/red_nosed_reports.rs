@@ -1,6 +0,0 @@
-use aoc::y2024::red_nosed_reports_02::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 2, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::reindeer_maze_16::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 16, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2020::report_repair_01::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2020, 1, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::resonant_collinearity_08::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 8, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2024::restroom_redoubt_14::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 14, do_solve)
-}
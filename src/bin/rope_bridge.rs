@@ -1,6 +0,0 @@
-use aoc::y2022::rope_bridge_09::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2022, 9, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2020::seating_system_11::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2020, 11, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2021::sonar_sweep_01::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2021, 1, do_solve)
-}
@@ -1,6 +0,0 @@
-use aoc::y2019::space_image_format_08::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2019, 8, do_solve)
-}
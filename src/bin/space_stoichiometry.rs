@@ -1,6 +0,0 @@
-use aoc::y2019::space_stoichiometry_14::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2019, 14, do_solve)
-}
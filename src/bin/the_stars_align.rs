@@ -1,6 +0,0 @@
-use aoc::y2018::the_stars_align_10::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2018, 10, do_solve)
-}
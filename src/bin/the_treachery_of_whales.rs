@@ -1,6 +0,0 @@
-use aoc::y2021::the_treachery_of_whales_07::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2021, 7, do_solve)
-}
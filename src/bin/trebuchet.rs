@@ -1,6 +0,0 @@
-use aoc::y2023::trebuchet_01::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2023, 1, do_solve)
-}
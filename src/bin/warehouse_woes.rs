@@ -1,6 +0,0 @@
-use aoc::y2024::warehouse_woes_15::do_solve;
-use std::io::Error;
-
-fn main() -> Result<(), Error> {
-    aoc::with_input(2024, 15, do_solve)
-}
@@ -9,15 +9,35 @@ mod font_x8;
 
 type Glyphs = HashMap<&'static str, char>;
 
+/// Blank columns between glyphs of a kerned font.
+const KERNING: usize = 2;
+
+/// The most columns any glyph takes up, including the blank ones after it.
+const MAX_GLYPH_SPAN: usize = 6 + KERNING;
+
+/// One of the AoC block printing fonts, each a different height.
 pub struct Font {
     width: Option<usize>,
     kerned: bool,
-    #[allow(dead_code)]
     height: usize,
     glyphs: &'static Glyphs,
 }
 
 impl Font {
+    /// The font whose glyphs are the given number of lines tall: 6, 8, or 10.
+    pub fn for_height(height: usize) -> Option<Font> {
+        match height {
+            6 => Some(font_5x6::get_font()),
+            8 => Some(font_x8::get_font()),
+            10 => Some(font_6x10::get_font()),
+            _ => None,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     fn is_fixed_width(&self) -> bool {
         self.width.is_some()
     }
@@ -26,18 +46,9 @@ impl Font {
         self.kerned
     }
 
-    fn recognize(&self, lines: &[&[char]]) -> Result<String, &'static str> {
+    fn recognize(&self, lines: &[Vec<char>]) -> Result<String, &'static str> {
         let mut result = String::new();
-        let mut buffer = String::new();
-        let line_len = lines[0].len();
-        let mut i = 0;
-        while i < line_len {
-            buffer.clear();
-            if self.is_fixed_width() {
-                self.read_fixed_width(lines, &mut i, self.width.unwrap(), &mut buffer);
-            } else {
-                self.read_variable_width(lines, &mut i, &mut buffer);
-            }
+        for buffer in self.split_glyphs(lines) {
             // I remain confused why a &String doesn't coerce to &str, and requires
             // an explicit [..] to slice it.
             if let Some(&c) = self.glyphs.get(&buffer[..]) {
@@ -46,25 +57,104 @@ impl Font {
                 eprintln!("Block printed character wasn't recognized.\n[{buffer}]",);
                 return Err("Failed to identify block-printed glyph.");
             }
+        }
+        Ok(result)
+    }
+
+    /// Whether every glyph in the lines is recognized, without any diagnostics.
+    fn recognizes(&self, lines: &[Vec<char>]) -> bool {
+        self.split_glyphs(lines)
+            .iter()
+            .all(|b| self.glyphs.contains_key(&b[..]))
+    }
+
+    /// Pads the lines on the right with blank columns, in case the final
+    /// glyph's trailing blank column(s) were cropped off.
+    fn pad(&self, lines: &mut [Vec<char>]) {
+        let Some(w) = self.width else {
+            return;
+        };
+        let len = lines[0].len();
+        let target = if self.is_kerned() {
+            len + w
+        } else {
+            len.next_multiple_of(w)
+        };
+        for l in lines {
+            l.resize(target, '.');
+        }
+    }
+
+    fn recognize_fuzzy(&self, lines: &[Vec<char>]) -> Result<Recognized, &'static str> {
+        let mut result = Recognized::default();
+        for buffer in self.split_glyphs(lines) {
+            if let Some(m) = self.best_match(&buffer) {
+                result.text.push(m.glyph);
+                result.glyphs.push(m);
+            } else {
+                eprintln!("Block printed character has no glyph its size.\n[{buffer}]",);
+                return Err("Failed to identify block-printed glyph.");
+            }
+        }
+        Ok(result)
+    }
+
+    /// The glyph the fewest pixels away from the passed one, among those of
+    /// the same size. Ties go to the earlier letter.
+    fn best_match(&self, buffer: &str) -> Option<GlyphMatch> {
+        let pixels = buffer.chars().filter(|&c| c != '\n').count();
+        self.glyphs
+            .iter()
+            .filter(|(g, _)| g.len() == buffer.len())
+            .map(|(g, &c)| {
+                let distance = g
+                    .chars()
+                    .zip(buffer.chars())
+                    .filter(|(a, b)| a != b)
+                    .count();
+                (distance, c)
+            })
+            .min()
+            .map(|(distance, glyph)| GlyphMatch {
+                glyph,
+                distance,
+                confidence: 1.0 - distance as f64 / pixels.max(1) as f64,
+            })
+    }
+
+    /// Splits the lines into a buffer per glyph, in the same layout as the
+    /// font's keys.
+    fn split_glyphs(&self, lines: &[Vec<char>]) -> Vec<String> {
+        let mut result = Vec::new();
+        let line_len = lines[0].len();
+        let mut i = 0;
+        while i < line_len {
+            let mut buffer = String::new();
+            if self.is_fixed_width() {
+                self.read_fixed_width(lines, &mut i, self.width.unwrap(), &mut buffer);
+            } else {
+                self.read_variable_width(lines, &mut i, &mut buffer);
+            }
+            result.push(buffer);
             if self.is_kerned() {
                 self.soak_up_spaces(lines, &mut i)
             }
         }
-        Ok(result)
+        result
     }
 
-    fn is_space(&self, lines: &[&[char]], i: usize) -> bool {
+    fn is_space(&self, lines: &[Vec<char>], i: usize) -> bool {
         lines.iter().all(|l| l[i] == '.')
     }
 
-    fn soak_up_spaces(&self, lines: &[&[char]], i: &mut usize) {
+    fn soak_up_spaces(&self, lines: &[Vec<char>], i: &mut usize) {
         let len = lines[0].len();
         while *i < len && self.is_space(lines, *i) {
             *i += 1;
         }
     }
 
-    fn read_variable_width(&self, lines: &[&[char]], i: &mut usize, buffer: &mut String) {
+    fn read_variable_width(&self, lines: &[Vec<char>], i: &mut usize, buffer: &mut String) {
         let len = lines[0].len();
         let mut end = *i + 1;
         while end < len && !self.is_space(lines, end) {
@@ -75,7 +165,7 @@ impl Font {
 
     fn read_fixed_width(
         &self,
-        lines: &[&[char]],
+        lines: &[Vec<char>],
         i: &mut usize,
         width: usize,
         buffer: &mut String,
@@ -89,81 +179,320 @@ impl Font {
     }
 }
 
+/// A block-printed glyph recognized by [parse_block_letters_fuzzy].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GlyphMatch {
+    pub glyph: char,
+    /// How many pixels differ from the font's glyph.
+    pub distance: usize,
+    /// The fraction of pixels which match the font's glyph, from zero to one.
+    pub confidence: f64,
+}
+
+/// The result of [parse_block_letters_fuzzy]: the recognized text, along with
+/// how well each of its glyphs matched.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Recognized {
+    pub text: String,
+    pub glyphs: Vec<GlyphMatch>,
+}
+
+impl Recognized {
+    /// The lowest confidence of any glyph, or one if there are none.
+    pub fn min_confidence(&self) -> f64 {
+        self.glyphs.iter().map(|g| g.confidence).fold(1.0, f64::min)
+    }
+}
+
 /// Parse block printing (a la 2016/08 Two-Factor Authentication) and turn it
 /// into an equivalent string of uppercase ASCII letters. All block printed
 /// strings are of uniform height, but individual glyphs may vary in width,
 /// depending on the font.
 ///
 /// Spaces and periods are considered "blank"; all other glyphs are considered
-/// "marked". Leading and trailing newlines will be trimmed. The final trailing
-/// column of the last character **MUST** be present, even if it's all blanks,
-/// when using fixed-width font. See the example below.
+/// "marked". Leading and trailing newlines will be trimmed. The last glyph's
+/// trailing blank column may be left off, as in the example below. To parse
+/// marked points or a bitmap instead of a string, see [parse_block_points] and
+/// [parse_block_bitmap].
 ///
-/// The full AoC font is not yet known, just enough to get Barney's answers. No
-/// guessing is performed; exact matches only. If parsing fails, an `Err` will
-/// be returned with a message about what went wrong, and some info on STDERR.
+/// Every font covers A-Z, though only some letters have been seen in actual
+/// puzzles; the rest are best guesses. No guessing is performed here; exact
+/// matches only (see [parse_block_letters_fuzzy] for otherwise). If parsing
+/// fails, an `Err` will be returned with a message about what went wrong, and
+/// some info on STDERR.
 ///
 /// ```
 /// # use aoc::block_print::parse_block_letters;
-/// // note the mandatory trailing spaces, shown as periods.
+/// // note the blank spaces, shown as periods.
 /// let display = r#"
-/// ABC   ██   ██..
-/// B  █ #  X O  0.
-/// B██  #  X O  0.
-/// B  █ #  X O  0.
-/// B  █ #  X O  0.
-/// def   ██   ██..
+/// ABC   ██   ██.
+/// B  █ #  X O  0
+/// B██  #  X O  0
+/// B  █ #  X O  0
+/// B  █ #  X O  0
+/// def   ██   ██.
 /// "#;
 ///
 /// assert_eq!(Ok("BOO".to_owned()), parse_block_letters(display));
 /// ```
 pub fn parse_block_letters(display: &str) -> Result<String, &str> {
-    let sanitized = display
-        .trim_matches(['\n', '\r'])
-        .chars()
-        .map(|c| match c {
-            ' ' => '.',
-            '.' | '\n' | '\r' => c,
-            _ => '#',
+    let mut lines = sanitize(display);
+    let font = font_for(&lines, display)?;
+    font.pad(&mut lines);
+    font.recognize(&lines)
+}
+
+/// Parse block printing like [parse_block_letters], but instead of requiring
+/// exact matches, pick whichever glyph of the same size is the fewest pixels
+/// different. Useful for noisy renderings, or glyphs no puzzle has shown yet.
+/// Check the returned confidences to decide whether to trust it.
+///
+/// ```
+/// # use aoc::block_print::parse_block_letters_fuzzy;
+/// // the H has lost a pixel
+/// let display = r#"
+/// █..█.█..█.
+/// █..█.█..█.
+/// ████.█..█.
+/// █....█..█.
+/// █..█.█..█.
+/// █..█..██..
+/// "#;
+///
+/// let r = parse_block_letters_fuzzy(display).unwrap();
+/// assert_eq!("HU", r.text);
+/// assert_eq!(0, r.glyphs[1].distance);
+/// assert_eq!(1, r.glyphs[0].distance);
+/// assert!(r.glyphs[0].confidence > 0.95);
+/// ```
+pub fn parse_block_letters_fuzzy(display: &str) -> Result<Recognized, &str> {
+    let mut lines = sanitize(display);
+    let font = font_for(&lines, display)?;
+    font.pad(&mut lines);
+    font.recognize_fuzzy(&lines)
+}
+
+/// Parse the block printing drawn by the passed marked points, like
+/// [parse_block_letters]. Points may be of any integer type, in any order, and
+/// at any offset; the drawing is cropped to their bounding box, and its height
+/// picks the font.
+///
+/// ```
+/// # use aoc::block_print::parse_block_points;
+/// let points = [
+///     (10, -3), (10, -2), (10, -1), (10, 0), (10, 1), (10, 2),
+///     (11, 2), (12, 2), (13, 2),
+/// ];
+///
+/// assert_eq!(Ok("L".to_owned()), parse_block_points(points));
+/// ```
+pub fn parse_block_points<I, T>(points: I) -> Result<String, &'static str>
+where
+    I: IntoIterator<Item = (T, T)>,
+    T: TryInto<i64>,
+{
+    let Some(points) = points
+        .into_iter()
+        .map(|(x, y)| Some((x.try_into().ok()?, y.try_into().ok()?)))
+        .collect::<Option<Vec<(i64, i64)>>>()
+    else {
+        return Err("Block printed points must fit in an i64.");
+    };
+    let Some(&(x, y)) = points.first() else {
+        return Err("Nothing is block printed.");
+    };
+    let (min, max) = points.iter().fold(((x, y), (x, y)), |(min, max), &(x, y)| {
+        ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+    });
+    let span = |lo: i64, hi: i64| {
+        hi.checked_sub(lo)
+            .and_then(|d| usize::try_from(d).ok())
+            .and_then(|d| d.checked_add(1))
+    };
+    let Some(height) = span(min.1, max.1).filter(|&h| Font::for_height(h).is_some()) else {
+        return Err("Block printed letters of unsupported height.");
+    };
+    // every glyph has a marked point, so far-flung points can't be letters
+    let Some(width) = span(min.0, max.0).filter(|&w| w <= points.len() * MAX_GLYPH_SPAN) else {
+        return Err("Block printed points are too far apart to be letters.");
+    };
+    let mut rows = vec![vec![false; width]; height];
+    for (x, y) in points {
+        rows[(y - min.1) as usize][(x - min.0) as usize] = true;
+    }
+    parse_block_rows(rows)
+}
+
+/// Parse the block printing drawn by the passed row-major bitmap, `width`
+/// pixels wide, like [parse_block_letters]. Marked pixels are `true`. The
+/// drawing is cropped to its marked pixels, and its height picks the font.
+///
+/// ```
+/// # use aoc::block_print::parse_block_bitmap;
+/// let bitmap = "
+/// ........
+/// .####...
+/// .#......
+/// .###....
+/// .#......
+/// .#......
+/// .#......"
+///     .chars()
+///     .filter(|&c| c != '\n')
+///     .map(|c| c == '#')
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(Ok("F".to_owned()), parse_block_bitmap(&bitmap, 8));
+/// ```
+pub fn parse_block_bitmap(pixels: &[bool], width: usize) -> Result<String, &'static str> {
+    if width == 0 || !pixels.len().is_multiple_of(width) {
+        eprintln!(
+            "Block printed bitmap of {} pixels can't be {width} wide.",
+            pixels.len()
+        );
+        return Err("Block printed bitmaps must be rectangular.");
+    }
+    parse_block_rows(pixels.chunks(width).map(|r| r.to_vec()).collect())
+}
+
+/// Crops the rows to their marked pixels, and recognizes them in the font of
+/// that height.
+fn parse_block_rows(rows: Vec<Vec<bool>>) -> Result<String, &'static str> {
+    let (Some(top), Some(bottom)) = (
+        rows.iter().position(|r| r.contains(&true)),
+        rows.iter().rposition(|r| r.contains(&true)),
+    ) else {
+        return Err("Nothing is block printed.");
+    };
+    let rows = &rows[top..=bottom];
+    let left = rows.iter().filter_map(|r| r.iter().position(|&b| b)).min();
+    let right = rows.iter().filter_map(|r| r.iter().rposition(|&b| b)).max();
+    let (left, right) = (left.unwrap(), right.unwrap());
+    let lines: Vec<Vec<char>> = rows
+        .iter()
+        .map(|r| {
+            r[left..=right]
+                .iter()
+                .map(|&b| if b { '#' } else { '.' })
+                .collect()
         })
-        .collect::<Vec<_>>();
-    let lines = sanitized.split(|&c| c == '\n').collect::<Vec<_>>();
-    let font = match lines.len() {
-        6 => font_5x6::get_font(),
-        8 => font_x8::get_font(),
-        10 => font_6x10::get_font(),
-        _ => {
-            eprintln!(
-                "Block printed letters with height {} are not supported.\n{display}",
-                lines.len()
-            );
-            return Err("Block printed letters of unsupported height.");
+        .collect();
+    let Some(font) = Font::for_height(lines.len()) else {
+        eprintln!(
+            "Block printed letters with height {} are not supported.\n{}",
+            lines.len(),
+            lines
+                .iter()
+                .map(|l| l.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        return Err("Block printed letters of unsupported height.");
+    };
+    // fixed-width glyphs may have had leading blank columns cropped, too
+    let leads = match font.width {
+        Some(w) if !font.is_kerned() => w,
+        _ => 1,
+    };
+    for lead in 0..leads {
+        let mut padded: Vec<Vec<char>> = lines
+            .iter()
+            .map(|l| {
+                std::iter::repeat_n('.', lead)
+                    .chain(l.iter().copied())
+                    .collect()
+            })
+            .collect();
+        font.pad(&mut padded);
+        if lead + 1 == leads || font.recognizes(&padded) {
+            return font.recognize(&padded);
         }
+    }
+    unreachable!()
+}
+
+fn sanitize(display: &str) -> Vec<Vec<char>> {
+    display
+        .trim_matches(['\n', '\r'])
+        .lines()
+        .map(|l| {
+            l.chars()
+                .map(|c| match c {
+                    ' ' | '.' => '.',
+                    _ => '#',
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Picks the font for the lines' height, and checks they're well formed for it.
+fn font_for(lines: &[Vec<char>], display: &str) -> Result<Font, &'static str> {
+    let Some(font) = Font::for_height(lines.len()) else {
+        eprintln!(
+            "Block printed letters with height {} are not supported.\n{display}",
+            lines.len()
+        );
+        return Err("Block printed letters of unsupported height.");
     };
-    let mut line_len = None;
+    let line_len = lines[0].len();
     for (i, l) in lines.iter().enumerate() {
         let len = l.len();
-        if let Some(ll) = line_len {
-            if ll != len {
-                eprintln!(
-                    "Block printed line {} has {len} characters, not {ll}.\n{display}",
-                    i + 1
-                );
-                return Err("Block printed lines are always the same width.");
-            }
-        } else if font.is_fixed_width() && !font.is_kerned() {
-            let w = font.width.unwrap();
-            if len % w != 0 {
-                eprintln!(
-                    "Block printed line {i} has {len} characters, which isn't divisible by {w}.\n{display}",
-                );
-                return Err("Block printed letters are always exactly five chars wide.");
+        if len != line_len {
+            eprintln!(
+                "Block printed line {} has {len} characters, not {line_len}.\n{display}",
+                i + 1
+            );
+            return Err("Block printed lines are always the same width.");
+        }
+    }
+    Ok(font)
+}
+
+/// Render uppercase ASCII letters as block printing in the passed font, the
+/// inverse of [parse_block_letters]. Marked pixels are drawn with `on`, and
+/// blanks with `off`; lines are separated by newlines, without a trailing one.
+/// Fixed-width glyphs include their trailing blank column, while kerned glyphs
+/// are separated by two blank columns.
+///
+/// If `text` has a character the font lacks, an `Err` will be returned, with
+/// some info on STDERR.
+///
+/// ```
+/// # use aoc::block_print::{render_block_letters, Font};
+/// let font = Font::for_height(6).unwrap();
+///
+/// assert_eq!(
+///     Ok(r"
+/// .##...##..
+/// #..#.#..#.
+/// #....#..#.
+/// #....#..#.
+/// #..#.#..#.
+/// .##...##..".trim_start().to_owned()),
+///     render_block_letters("CO", &font, '#', '.')
+/// );
+/// ```
+pub fn render_block_letters(
+    text: &str,
+    font: &Font,
+    on: char,
+    off: char,
+) -> Result<String, &'static str> {
+    let mut lines = vec![String::new(); font.height];
+    for (i, c) in text.chars().enumerate() {
+        let Some((glyph, _)) = font.glyphs.iter().find(|(_, &g)| g == c) else {
+            eprintln!("No block printed glyph for '{c}' in {text:?}.");
+            return Err("Unsupported character for block printing.");
+        };
+        for (line, row) in lines.iter_mut().zip(glyph.trim_start().lines()) {
+            if font.is_kerned() && i > 0 {
+                line.extend([off; KERNING]);
             }
-        } else {
-            line_len = Some(len)
+            line.extend(row.chars().map(|p| if p == '#' { on } else { off }));
         }
     }
-    font.recognize(&lines)
+    Ok(lines.join("\n"))
 }
 
 #[cfg(test)]
@@ -184,6 +513,181 @@ mod test {
         }
     }
 
+    #[test]
+    fn full_alphabets() {
+        let az: String = ('A'..='Z').collect();
+        for height in [6, 8, 10] {
+            let font = Font::for_height(height).unwrap();
+            let display = render_block_letters(&az, &font, BLOCK, ' ').unwrap();
+            assert_eq!(Ok(az.clone()), parse_block_letters(&display), "{display}");
+            let r = parse_block_letters_fuzzy(&display).unwrap();
+            assert_eq!(az, r.text);
+            assert_eq!(1.0, r.min_confidence());
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        // a pseudo-random walk over the alphabet, so every letter gets a turn
+        // next to a variety of others
+        let mut n = 7usize;
+        let words: Vec<String> = (1..=40)
+            .map(|len| {
+                (0..len)
+                    .map(|_| {
+                        n = (n * 31 + 11) % 26;
+                        (b'A' + n as u8) as char
+                    })
+                    .collect()
+            })
+            .collect();
+        for height in [6, 8, 10] {
+            let font = Font::for_height(height).unwrap();
+            for (on, off) in [(BLOCK, '.'), ('#', '.'), ('#', ' ')] {
+                for w in &words {
+                    let display = render_block_letters(w, &font, on, off).unwrap();
+                    assert_eq!(height, display.lines().count());
+                    assert_eq!(Ok(w.clone()), parse_block_letters(&display), "{display}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn renders_known_displays() {
+        let render = |text, height| {
+            let font = Font::for_height(height).unwrap();
+            render_block_letters(text, &font, BLOCK, '.').unwrap()
+        };
+        assert_eq!(
+            "
+█...█..███
+█...█...█.
+█...█...█.
+█████...█.
+█...█...█.
+█...█...█.
+█...█...█.
+█...█..███",
+            format!("\n{}", render("HI", 8))
+        );
+        assert_eq!(
+            "
+█████.....██....██████
+█....█...█..█........█
+█....█..█....█.......█
+█....█..█....█......█.
+█████...█....█.....█..
+█..█....██████....█...
+█...█...█....█...█....
+█...█...█....█..█.....
+█....█..█....█..█.....
+█....█..█....█..██████",
+            format!("\n{}", render("RAZ", 10))
+        );
+    }
+
+    #[test]
+    fn render_unsupported() {
+        let font = Font::for_height(6).unwrap();
+        assert!(render_block_letters("Hi", &font, '#', '.').is_err());
+        assert!(render_block_letters("A B", &font, '#', '.').is_err());
+        assert_eq!(
+            Ok("\n".repeat(5)),
+            render_block_letters("", &font, '#', '.')
+        );
+    }
+
+    /// The marked points of the rendered text, shifted by `offset`.
+    fn points(text: &str, height: usize, offset: i32) -> Vec<(i32, i32)> {
+        let font = Font::for_height(height).unwrap();
+        render_block_letters(text, &font, '#', '.')
+            .unwrap()
+            .lines()
+            .enumerate()
+            .flat_map(|(y, l)| {
+                l.char_indices()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| (x as i32 + offset, y as i32 - offset))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn from_points() {
+        // 'I' starts, and 'D' and 'P' end, with blank columns
+        for (text, height) in [
+            ("IDEA", 6),
+            ("TOAD", 6),
+            ("HELP", 10),
+            ("JUMP", 8),
+            ("XYZ", 10),
+        ] {
+            let pts = points(text, height, -7);
+            assert_eq!(Ok(text.to_owned()), parse_block_points(pts.clone()));
+            let pts = pts
+                .into_iter()
+                .map(|(x, y)| (x as i64 + 100, y as i64 + 100));
+            assert_eq!(Ok(text.to_owned()), parse_block_points(pts));
+        }
+        let usizes = points("HI", 8, 0)
+            .into_iter()
+            .map(|(x, y)| (x as usize, y as usize));
+        assert_eq!(Ok("HI".to_owned()), parse_block_points(usizes));
+        assert!(parse_block_points(Vec::<(u8, u8)>::new()).is_err());
+        assert!(parse_block_points([(0, 0), (1, 1)]).is_err());
+        assert!(parse_block_points([(u64::MAX, 0)]).is_err());
+        // boxes no font could need are rejected before drawing them
+        assert!(parse_block_points([(i64::MIN, 0), (i64::MAX, 5)]).is_err());
+        assert!(parse_block_points([(0, i64::MIN), (0, i64::MAX)]).is_err());
+        assert!(parse_block_points([(0, 0), (1_000_000_000, 5)]).is_err());
+    }
+
+    #[test]
+    fn from_bitmap() {
+        let pts = points("BEEF", 6, 0);
+        let (width, height) = (30, 9);
+        let mut bitmap = vec![false; width * height];
+        for (x, y) in pts {
+            bitmap[(y as usize + 2) * width + x as usize + 3] = true;
+        }
+        assert_eq!(Ok("BEEF".to_owned()), parse_block_bitmap(&bitmap, width));
+        assert!(parse_block_bitmap(&bitmap, 29).is_err());
+        assert!(parse_block_bitmap(&bitmap, 0).is_err());
+        assert!(parse_block_bitmap(&[false; 30], 10).is_err());
+    }
+
+    #[test]
+    fn missing_trailing_column() {
+        let display = "
+###...##.
+#..#.#..#
+#..#.#..#
+###..#..#
+#....#..#
+#.....##.";
+        assert_eq!(Ok("PO".to_string()), parse_block_letters(display));
+    }
+
+    #[test]
+    fn fuzzy_flipped_pixel() {
+        // the K's lower leg is missing a pixel, and the S has an extra one
+        let display = r"
+.██...██...██..█..█.█....████.███..█..█.███...███.
+█..█.█..█.█..█.█.█..█....█....█..█.█..█.█..█.█....
+█....█..█.█....██...█....███..███..█..█.█..█.█....
+█....█..█.█....█.█..█....█....█..█.█..█.███...██..
+█..█.█..█.█..█.█....█....█....█..█.█..█.█.█.....█.
+.██...██...██..█..█.████.████.███...██..█..█.███.█
+";
+        assert!(parse_block_letters(display).is_err());
+        let r = parse_block_letters_fuzzy(display).unwrap();
+        assert_eq!("COCKLEBURS", r.text);
+        let distances: Vec<_> = r.glyphs.iter().map(|g| g.distance).collect();
+        assert_eq!(vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 1], distances);
+        assert!(r.min_confidence() < 1.0);
+    }
+
     #[test]
     fn cockleburs_5x6() {
         let display = r"
@@ -2,9 +2,16 @@ use crate::block_print::Font;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+#[rustfmt::skip]
 lazy_static! {
     static ref BLOCK_GLYPHS: HashMap<&'static str, char> = HashMap::from([
-        // A
+        ("
+.##..
+#..#.
+#..#.
+####.
+#..#.
+#..#.", 'A'),
         ("
 ###..
 #..#.
@@ -19,7 +26,13 @@ lazy_static! {
 #....
 #..#.
 .##..", 'C'),
-        // D
+        ("
+###..
+#..#.
+#..#.
+#..#.
+#..#.
+###..", 'D'),
         ("
 ####.
 #....
@@ -48,7 +61,13 @@ lazy_static! {
 #..#.
 #..#.
 #..#.", 'H'),
-        // I
+        ("
+.###.
+..#..
+..#..
+..#..
+..#..
+.###.", 'I'),
         ("
 ..##.
 ...#.
@@ -70,8 +89,20 @@ lazy_static! {
 #....
 #....
 ####.", 'L'),
-        // M
-        // N
+        ("
+#...#
+##.##
+#.#.#
+#...#
+#...#
+#...#", 'M'),
+        ("
+#...#
+##..#
+#.#.#
+#..##
+#...#
+#...#", 'N'),
         ("
 .##..
 #..#.
@@ -86,7 +117,13 @@ lazy_static! {
 ###..
 #....
 #....", 'P'),
-        // Q
+        ("
+.##..
+#..#.
+#..#.
+#..#.
+#.#..
+.#.#.", 'Q'),
         ("
 ###..
 #..#.
@@ -101,7 +138,13 @@ lazy_static! {
 .##..
 ...#.
 ###..", 'S'),
-        // T
+        ("
+#####
+..#..
+..#..
+..#..
+..#..
+..#..", 'T'),
         ("
 #..#.
 #..#.
@@ -109,9 +152,27 @@ lazy_static! {
 #..#.
 #..#.
 .##..", 'U'),
-        // V
-        // W
-        // X
+        ("
+#...#
+#...#
+#...#
+.#.#.
+.#.#.
+..#..", 'V'),
+        ("
+#...#
+#...#
+#...#
+#.#.#
+##.##
+#...#", 'W'),
+        ("
+#...#
+.#.#.
+..#..
+..#..
+.#.#.
+#...#", 'X'),
         ("
 #...#
 #...#
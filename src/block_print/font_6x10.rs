@@ -2,6 +2,7 @@ use crate::block_print::Font;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+#[rustfmt::skip]
 lazy_static! {
     static ref BLOCK_GLYPHS: HashMap<&'static str, char> = HashMap::from([
         ("
@@ -39,8 +40,28 @@ lazy_static! {
 #.....
 #....#
 .####.", 'C'),
-        // D
-        // E
+        ("
+#####.
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#####.", 'D'),
+        ("
+######
+#.....
+#.....
+#.....
+#####.
+#.....
+#.....
+#.....
+#.....
+######", 'E'),
         ("
 ######
 #.....
@@ -75,7 +96,17 @@ lazy_static! {
 #....#
 #....#", 'H'
         ),
-        // I
+        ("
+######
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..
+######", 'I'),
         ("
 ...###
 ....#.
@@ -110,7 +141,17 @@ lazy_static! {
 #.....
 #.....
 ######", 'L'),
-        // M
+        ("
+#....#
+##..##
+#.##.#
+#.##.#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#", 'M'),
         ("
 #....#
 ##...#
@@ -122,7 +163,17 @@ lazy_static! {
 #...##
 #...##
 #....#", 'N'),
-        // O
+        ("
+.####.
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+.####.", 'O'),
         ("
 #####.
 #....#
@@ -134,7 +185,17 @@ lazy_static! {
 #.....
 #.....
 #.....", 'P'),
-        // Q
+        ("
+.####.
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#..#.#
+#...#.
+.###.#", 'Q'),
         ("
 #####.
 #....#
@@ -147,11 +208,61 @@ lazy_static! {
 #....#
 #....#", 'R'
         ),
-        // S
-        // T
-        // U
-        // V
-        // W
+        ("
+.####.
+#....#
+#.....
+#.....
+.####.
+.....#
+.....#
+.....#
+#....#
+.####.", 'S'),
+        ("
+######
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..", 'T'),
+        ("
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+#....#
+.####.", 'U'),
+        ("
+#....#
+#....#
+#....#
+#....#
+.#..#.
+.#..#.
+.#..#.
+..##..
+..##..
+..##..", 'V'),
+        ("
+#....#
+#....#
+#....#
+#....#
+#....#
+#.##.#
+#.##.#
+##..##
+##..##
+#....#", 'W'),
         ("
 #....#
 #....#
@@ -164,7 +275,17 @@ lazy_static! {
 #....#
 #....#", 'X'
         ),
-        // Y
+        ("
+#....#
+#....#
+.#..#.
+.#..#.
+..##..
+..##..
+..##..
+..##..
+..##..
+..##..", 'Y'),
         ("
 ######
 .....#
@@ -184,7 +305,7 @@ pub fn get_font() -> super::Font {
     Font {
         width: Some(6),
         kerned: true,
-        height: 6,
+        height: 10,
         glyphs: &*BLOCK_GLYPHS,
     }
 }
@@ -2,9 +2,72 @@ use crate::block_print::Font;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
+#[rustfmt::skip]
 lazy_static! {
     static ref BLOCK_GLYPHS: HashMap<&'static str, char> = HashMap::from([
-        // ABCDEFG
+        ("
+.###.
+#...#
+#...#
+#...#
+#####
+#...#
+#...#
+#...#", 'A'),
+        ("
+####.
+#...#
+#...#
+####.
+#...#
+#...#
+#...#
+####.", 'B'),
+        ("
+.###.
+#...#
+#....
+#....
+#....
+#....
+#...#
+.###.", 'C'),
+        ("
+####.
+#...#
+#...#
+#...#
+#...#
+#...#
+#...#
+####.", 'D'),
+        ("
+#####
+#....
+#....
+####.
+#....
+#....
+#....
+#####", 'E'),
+        ("
+#####
+#....
+#....
+####.
+#....
+#....
+#....
+#....", 'F'),
+        ("
+.###.
+#...#
+#....
+#....
+#..##
+#...#
+#...#
+.###.", 'G'),
         ("
 #...#
 #...#
@@ -25,7 +88,159 @@ lazy_static! {
 .#.
 ###", 'I'
         ),
-        // JKLMNOPQRSTUVWXYZ
+        ("
+..###
+...#.
+...#.
+...#.
+...#.
+...#.
+#..#.
+.##..", 'J'),
+        ("
+#...#
+#..#.
+#.#..
+##...
+##...
+#.#..
+#..#.
+#...#", 'K'),
+        ("
+#....
+#....
+#....
+#....
+#....
+#....
+#....
+#####", 'L'),
+        ("
+#...#
+##.##
+#.#.#
+#.#.#
+#...#
+#...#
+#...#
+#...#", 'M'),
+        ("
+#...#
+##..#
+##..#
+#.#.#
+#.#.#
+#..##
+#..##
+#...#", 'N'),
+        ("
+.###.
+#...#
+#...#
+#...#
+#...#
+#...#
+#...#
+.###.", 'O'),
+        ("
+####.
+#...#
+#...#
+####.
+#....
+#....
+#....
+#....", 'P'),
+        ("
+.###.
+#...#
+#...#
+#...#
+#...#
+#.#.#
+#..#.
+.##.#", 'Q'),
+        ("
+####.
+#...#
+#...#
+####.
+#.#..
+#..#.
+#...#
+#...#", 'R'),
+        ("
+.###.
+#...#
+#....
+.###.
+....#
+....#
+#...#
+.###.", 'S'),
+        ("
+#####
+..#..
+..#..
+..#..
+..#..
+..#..
+..#..
+..#..", 'T'),
+        ("
+#...#
+#...#
+#...#
+#...#
+#...#
+#...#
+#...#
+.###.", 'U'),
+        ("
+#...#
+#...#
+#...#
+#...#
+.#.#.
+.#.#.
+..#..
+..#..", 'V'),
+        ("
+#...#
+#...#
+#...#
+#...#
+#.#.#
+#.#.#
+##.##
+#...#", 'W'),
+        ("
+#...#
+#...#
+.#.#.
+..#..
+..#..
+.#.#.
+#...#
+#...#", 'X'),
+        ("
+#...#
+#...#
+.#.#.
+..#..
+..#..
+..#..
+..#..
+..#..", 'Y'),
+        ("
+#####
+....#
+...#.
+..#..
+.#...
+#....
+#....
+#####", 'Z'),
     ]);
 }
 
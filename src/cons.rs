@@ -0,0 +1,228 @@
+//! A persistent, singly-linked [Cons] list. Pushing returns a new list sharing
+//! the old one as its tail, so many search states can extend a common path
+//! without each cloning a `Vec` of it.
+//!
+//! ```
+//! # use aoc::cons::{union, Cons};
+//! let trunk = Cons::of('a').push('b');
+//! let left = trunk.push('c');
+//! let right = trunk.push('d').push('e');
+//!
+//! assert_eq!(vec!['a', 'b', 'c'], left.to_vec());
+//! assert_eq!(4, right.len());
+//! assert_eq!(Some(&'e'), right.head());
+//! assert_eq!(5, union([&left, &right]).len());
+//! ```
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::rc::Rc;
+
+struct Node<T> {
+    value: T,
+    tail: Cons<T>,
+    len: usize,
+}
+
+/// A persistent list, whose head is the most recently pushed element. Cloning
+/// is cheap, sharing every element.
+pub struct Cons<T> {
+    head: Option<Rc<Node<T>>>,
+}
+
+impl<T> Cons<T> {
+    /// An empty list.
+    pub fn new() -> Cons<T> {
+        Cons { head: None }
+    }
+
+    /// A list of a single element.
+    pub fn of(t: T) -> Cons<T> {
+        Cons::new().push(t)
+    }
+
+    /// A new list of the passed element, followed by this list.
+    pub fn push(&self, t: T) -> Cons<T> {
+        Cons {
+            head: Some(Rc::new(Node {
+                value: t,
+                tail: self.clone(),
+                len: self.len() + 1,
+            })),
+        }
+    }
+
+    /// The most recently pushed element, if any.
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|n| &n.value)
+    }
+
+    /// Everything but the head, if any.
+    pub fn tail(&self) -> Option<&Cons<T>> {
+        self.head.as_ref().map(|n| &n.tail)
+    }
+
+    /// The number of elements, in constant time.
+    pub fn len(&self) -> usize {
+        self.head.as_ref().map_or(0, |n| n.len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Iterates from the head, so in the reverse of the order pushed.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// The elements in the order they were pushed.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut v: Vec<_> = self.iter().cloned().collect();
+        v.reverse();
+        v
+    }
+}
+
+impl<T> Default for Cons<T> {
+    fn default() -> Self {
+        Cons::new()
+    }
+}
+
+impl<T> Clone for Cons<T> {
+    fn clone(&self) -> Self {
+        Cons {
+            head: self.head.clone(),
+        }
+    }
+}
+
+/// Unlinks iteratively, as the default recursive drop would overflow the stack
+/// for long lists.
+impl<T> Drop for Cons<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(rc) = next {
+            match Rc::try_unwrap(rc) {
+                Ok(mut node) => next = node.tail.head.take(),
+                // still shared, so nothing further to drop
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Cons<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Pushes each element in turn, so the last becomes the head.
+impl<T> FromIterator<T> for Cons<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().fold(Cons::new(), |l, t| l.push(t))
+    }
+}
+
+/// Iterates a [Cons] from its head.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = node.tail.head.as_deref();
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.next.map_or(0, |n| n.len);
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a Cons<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Every distinct element of any of the passed lists, such as every cell on
+/// any of several tied-best paths. Shared tails are only walked once.
+pub fn union<'a, T, I>(lists: I) -> HashSet<T>
+where
+    T: Clone + Eq + Hash + 'a,
+    I: IntoIterator<Item = &'a Cons<T>>,
+{
+    let mut seen_nodes = HashSet::new();
+    let mut values = HashSet::new();
+    for list in lists {
+        let mut next = list.head.as_ref();
+        while let Some(node) = next {
+            if !seen_nodes.insert(Rc::as_ptr(node)) {
+                break;
+            }
+            values.insert(node.value.clone());
+            next = node.tail.head.as_ref();
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let empty: Cons<i32> = Cons::new();
+        assert!(empty.is_empty());
+        assert_eq!(None, empty.head());
+        assert!(empty.tail().is_none());
+        let list: Cons<_> = (1..=3).collect();
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&3), list.head());
+        assert_eq!(Some(&2), list.tail().and_then(|t| t.head()));
+        assert_eq!(vec![3, 2, 1], list.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![1, 2, 3], list.to_vec());
+        assert_eq!(3, list.iter().len());
+        assert_eq!("[3, 2, 1]", format!("{list:?}"));
+    }
+
+    #[test]
+    fn sharing() {
+        let trunk = Cons::of(1).push(2);
+        let a = trunk.push(3);
+        let b = trunk.push(4);
+        drop(trunk);
+        assert_eq!(vec![1, 2, 3], a.to_vec());
+        assert_eq!(vec![1, 2, 4], b.to_vec());
+        let mut all: Vec<_> = union([&a, &b]).into_iter().collect();
+        all.sort();
+        assert_eq!(vec![1, 2, 3, 4], all);
+        // equal values on unshared nodes are still merged
+        assert_eq!(2, union([&Cons::of(1).push(2), &Cons::of(2)]).len());
+    }
+
+    #[test]
+    fn long_lists_drop() {
+        let list: Cons<_> = (0..1_000_000).collect();
+        let shared = list.tail().unwrap().clone();
+        drop(list);
+        assert_eq!(999_999, shared.len());
+    }
+}
@@ -1,11 +1,23 @@
+use crate::answers::{Answers, Mode, Verdict};
 use crate::aocd;
-use console::{style, Style, StyledObject};
-use std::io::Error;
+use crate::aocd::AocdError;
+use crate::timing::profile;
+use console::{style, Style, StyledObject, Term};
+use serde_json::{json, Value};
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Error};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::mpsc::{channel, Receiver, RecvError, Sender};
-use std::sync::{Arc, RwLock};
+use std::sync::mpsc::{channel, RecvError, RecvTimeoutError, Sender};
 use std::time::{Duration, Instant};
-use std::{env, io, thread};
+use std::{env, thread};
+
+/// Environment variable which, when set (to anything but `0`), makes
+/// [with_input] print a JSON object per [Part], one per line, instead of its
+/// usual human-readable output. See [Part::to_json] for the format. Verified
+/// parts also have a `verdict` of `correct`, `wrong`, or `unverified`.
+pub const JSON_EVENTS_VAR: &str = "BEB_JSON_EVENTS";
 
 #[derive(Debug)]
 pub enum Part {
@@ -15,197 +27,534 @@ pub enum Part {
     B(String),
     Both(String, String),
     Other(String),
+    /// Sent once the solver's thread is joined, with its total solve time.
     Join(),
+    /// How far along a long-running solver is, shown as a progress bar until
+    /// the next part is printed. Doesn't reset the timing between parts, and
+    /// isn't printed in JSON events mode. Updates are rendered at most every
+    /// [PROGRESS_INTERVAL], but a solver sending millions will still slow
+    /// itself down; send them per-batch or per-branch instead.
+    Progress {
+        done: usize,
+        total: usize,
+        label: String,
+    },
 }
 
+impl Part {
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Part::Parse() => "parse",
+            Part::Parsed(_) => "parsed",
+            Part::A(_) => "a",
+            Part::B(_) => "b",
+            Part::Both(_, _) => "both",
+            Part::Other(_) => "other",
+            Part::Join() => "join",
+            Part::Progress { .. } => "progress",
+        }
+    }
+
+    /// Renders this part as a single-line JSON object, with its `kind`, the
+    /// `nanos` since the previous part (or for [Part::Join], since the solver
+    /// started), and its `answer`, if it has one. Both parts' answers are an
+    /// array.
+    ///
+    /// ```
+    /// # use aoc::Part;
+    /// # use std::time::Duration;
+    /// let json = Part::A("4]]2".to_string()).to_json(Duration::from_nanos(123));
+    /// assert_eq!(r#"{"answer":"4]]2","kind":"a","nanos":123}"#, json);
+    /// ```
+    pub fn to_json(&self, duration: Duration) -> String {
+        self.json_value(duration).to_string()
+    }
+
+    fn json_value(&self, duration: Duration) -> Value {
+        let mut obj = json!({
+            "kind": self.kind(),
+            "nanos": duration.as_nanos() as u64,
+        });
+        let answer = match self {
+            Part::Parsed(a) | Part::A(a) | Part::B(a) | Part::Other(a) => json!(a),
+            Part::Both(a, b) => json!([a, b]),
+            Part::Progress { done, total, label } => {
+                obj["done"] = json!(done);
+                obj["total"] = json!(total);
+                obj["label"] = json!(label);
+                return obj;
+            }
+            Part::Parse() | Part::Join() => return obj,
+        };
+        obj["answer"] = answer;
+        obj
+    }
+}
+
+/// Environment variable giving the default wall-clock limit, in (possibly
+/// fractional) seconds, on each solver run via [with_input]. If unset, solvers
+/// may run as long as they like. See [default_timeout].
+pub const TIMEOUT_VAR: &str = "BEB_TIMEOUT";
+
+/// The minimum time between redraws of a [Part::Progress] bar.
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How a solver's run ended. Any parts answered before a timeout or panic are
+/// still printed and verified.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Completed,
+    /// The solver was still running after the given timeout. It's left to
+    /// finish (or not) on its own thread, with any further answers ignored.
+    Timeout(Duration),
+    /// The solver panicked, with the given message.
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn is_completed(&self) -> bool {
+        *self == Outcome::Completed
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Completed => write!(f, "Completed"),
+            Outcome::Timeout(d) => write!(f, "Timed out after {d:?}"),
+            Outcome::Panicked(msg) => write!(f, "Panicked: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Outcome {}
+
 /// Invokes the passed `work`, passing it the given year/day's input as a
 /// `String`, and a `Sender` which accepts [Part]-wrapped answers to be printed
-/// and verified.
+/// and verified. If `work` panics or exceeds the [TIMEOUT_VAR] limit, the
+/// returned error wraps the [Outcome]. Fails without running `work` if the
+/// [Answers] file can't be read. Any [profile] spans it recorded, on its
+/// own thread or any it spawned, are reported afterward.
 pub fn with_input<S>(year: u32, day: u8, work: S) -> Result<(), Error>
 where
     S: FnOnce(&str, Sender<Part>) -> (),
     S: Send + 'static,
 {
-    let external_run = env::var("BEB_EXTERNAL_RUN")
-        .map(|v| v != "0")
-        .unwrap_or(false);
-    let solve_nanos = env::var("BEB_SOLVE_NANOS")
-        .map(|v| v != "0")
-        .unwrap_or(false);
+    with_input_timeout(year, day, work, default_timeout())
+}
+
+/// Invokes the passed `work` like [with_input], but with the given timeout,
+/// rather than the [TIMEOUT_VAR] default.
+pub fn with_input_timeout<S>(
+    year: u32,
+    day: u8,
+    work: S,
+    timeout: Option<Duration>,
+) -> Result<(), Error>
+where
+    S: FnOnce(&str, Sender<Part>) + Send + 'static,
+{
+    let json_events = json_events();
+    let answers = load_answers()?;
     let (print_tx, print_rx) = channel();
     let print_handle = thread::spawn(move || {
-        let print = Print::new();
+        let mut print = Print::new(answers);
         let mut correct = true;
         loop {
             match print_rx.recv() {
-                Ok((p, dur)) => {
-                    if external_run {
-                        match p {
-                            Part::A(a) => {
-                                println!("[__AOCD_VERIFY_A__[{}]]", a);
-                            }
-                            Part::B(b) => {
-                                println!("[__AOCD_VERIFY_B__[{}]]", b);
-                            }
-                            Part::Both(a, b) => {
-                                println!("[__AOCD_VERIFY_A__[{}]]", a);
-                                println!("[__AOCD_VERIFY_B__[{}]]", b);
-                            }
-                            _ => {}
-                        }
-                    } else {
-                        correct &= print.print(year, day, &p, dur)
-                    }
-                }
+                Ok((p, dur)) => correct &= print.print_part(json_events, year, day, &p, dur),
                 Err(RecvError) => break,
             }
         }
         correct
     });
 
+    let input = match aocd::get_input(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            print_input_error(&e);
+            return Err(e.into());
+        }
+    };
+    let (outcome, nodes) = solve_timed(thread_name(year, day), &input, work, print_tx, timeout);
+    let correct = print_handle
+        .join()
+        .expect("Print thread should have exited cleanly");
+    report_profile(&profile::merge(nodes, profile::take()));
+    finish(outcome, correct)
+}
+
+/// What a solver sent and how it ended, from [collect].
+#[derive(Debug)]
+pub struct Collected {
+    /// The [Part]s a solver sent, each with its duration, plus the trailing
+    /// [Part::Join] if it completed.
+    /// [Part::Progress] updates are dropped, as they'd be stale by the time
+    /// they're reported.
+    pub parts: Vec<(Part, Duration)>,
+    pub outcome: Outcome,
+    /// The [profile] spans the solver recorded on its own thread, if it
+    /// completed. Those on threads it spawned are left in the shared registry.
+    pub profile: Vec<profile::Node>,
+}
+
+/// Invokes the passed `work` like [with_input], but collects its parts for a
+/// later [report], instead of printing and verifying them as they arrive.
+/// Collecting many days on separate threads is safe, as each gets its own
+/// channel, timings and profile. Gives up on `work` if it outlives the
+/// `timeout`.
+pub fn collect<S>(
+    year: u32,
+    day: u8,
+    work: S,
+    timeout: Option<Duration>,
+) -> Result<Collected, AocdError>
+where
+    S: FnOnce(&str, Sender<Part>) + Send + 'static,
+{
     let input = aocd::get_input(year, day)?;
-    let (solve_tx, solve_rx) = channel();
-    let time_arc = Arc::new(RwLock::new(Instant::now()));
-    let answer_handle = {
-        let ptx = print_tx.clone();
-        let answer_time = time_arc.clone();
-        thread::spawn(move || listen_for_answers(answer_time, solve_rx, ptx))
+    let (tx, rx) = channel();
+    let (outcome, profile) = solve_timed(thread_name(year, day), &input, work, tx, timeout);
+    Ok(Collected {
+        parts: rx
+            .into_iter()
+            .filter(|(p, _)| !matches!(p, Part::Progress { .. }))
+            .collect(),
+        outcome,
+        profile,
+    })
+}
+
+/// Prints and verifies the result of a [collect], and reports its profile,
+/// returning the same result [with_input] would have.
+pub fn report(year: u32, day: u8, collected: Result<Collected, AocdError>) -> Result<(), Error> {
+    let collected = match collected {
+        Ok(c) => c,
+        Err(e) => {
+            print_input_error(&e);
+            return Err(e.into());
+        }
     };
-    {
-        let mut t = time_arc.write().unwrap();
-        *t = Instant::now();
+    let json_events = json_events();
+    let mut print = Print::new(load_answers()?);
+    let mut correct = true;
+    for (p, dur) in collected.parts {
+        correct &= print.print_part(json_events, year, day, &p, dur);
     }
+    report_profile(&collected.profile);
+    finish(collected.outcome, correct)
+}
 
-    let solve_nanos_start = Instant::now();
-    work(input.trim_end_matches('\n'), solve_tx);
-    answer_handle
-        .join()
-        .expect("Answer thread should have exited cleanly");
-    let solve_elapsed = solve_nanos_start.elapsed();
-    if solve_nanos {
-        println!("¡¡solve nanos {}!!", solve_elapsed.as_nanos());
-    } else if external_run {
-        println!("[__AOCD_VERIFY_T__[{}]]", solve_elapsed.as_nanos());
-    } else {
-        print_tx.send((Part::Join(), solve_elapsed)).unwrap();
-    }
-    drop(print_tx); // since cloned above
-    if print_handle
-        .join()
-        .expect("Print thread should have exited cleanly")
-    {
-        Ok(())
-    } else {
-        Err(Error::new(
-            io::ErrorKind::Other,
-            "Incorrect answer(s) provided.",
-        ))
+/// Reports [profile] nodes as directed by [profile::PROFILE_VAR], complaining
+/// if they can't be written.
+pub fn report_profile(nodes: &[profile::Node]) {
+    if let Err(e) = profile::report(nodes) {
+        eprintln!("{}", style(format!("Failed to write profile: {e}")).red());
     }
 }
 
-fn listen_for_answers(
-    time: Arc<RwLock<Instant>>,
-    solve_rx: Receiver<Part>,
+fn json_events() -> bool {
+    env::var(JSON_EVENTS_VAR).map(|v| v != "0").unwrap_or(false)
+}
+
+/// The timeout given by [TIMEOUT_VAR], if it's set to a number of seconds.
+pub fn default_timeout() -> Option<Duration> {
+    env::var(TIMEOUT_VAR)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+}
+
+fn finish(outcome: Outcome, correct: bool) -> Result<(), Error> {
+    let kind = match outcome {
+        Outcome::Completed if correct => return Ok(()),
+        Outcome::Completed => return Err(Error::other("Incorrect answer(s) provided.")),
+        Outcome::Timeout(_) => io::ErrorKind::TimedOut,
+        Outcome::Panicked(_) => io::ErrorKind::Other,
+    };
+    println!("{}", style(&outcome).red().bold());
+    Err(Error::new(kind, outcome))
+}
+
+/// Loads the [Answers] store, printing why if it can't be read, rather than
+/// leaving the printer to find out part way through.
+fn load_answers() -> Result<Answers, Error> {
+    Answers::load().inspect_err(|e| {
+        let msg = format!("Failed to load answers: {e}");
+        println!("{}", style(msg).red().bold());
+    })
+}
+
+fn print_input_error(e: &AocdError) {
+    let msg = match e {
+        AocdError::ToolMissing(..) => format!("{e}. Is it installed, or is the input cached?"),
+        AocdError::ExpiredToken => format!("{e}!"),
+        e => format!("Failed to get input: {e}"),
+    };
+    println!("{}", style(msg).red().bold());
+}
+
+/// The stack size for solver threads. Solvers used to run on the main thread,
+/// which usually gets 8 MiB, and some recurse deeply, so be generous.
+const SOLVER_STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// Runs `work` on `input` on its own thread, named `name`, sending each part it
+/// answers to `print_tx` along with its duration. If it completes, its total solve time
+/// follows as a [Part::Join], and the [profile] it recorded on its own thread
+/// is returned. Gives up on `work` if it outlives the `timeout`.
+fn solve_timed<S>(
+    name: String,
+    input: &str,
+    work: S,
     print_tx: Sender<(Part, Duration)>,
-) {
+    timeout: Option<Duration>,
+) -> (Outcome, Vec<profile::Node>)
+where
+    S: FnOnce(&str, Sender<Part>) + Send + 'static,
+{
+    let (solve_tx, solve_rx) = channel();
+    let input = input.trim_end_matches('\n').to_string();
+    let worker = thread::Builder::new()
+        .name(name)
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let start = Instant::now();
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                profile::capture(|| work(&input, solve_tx))
+            }))
+            .map(|((), nodes)| (start.elapsed(), nodes))
+            .map_err(|payload| panic_message(&*payload))
+        })
+        .expect("Solver thread should have spawned");
+    let deadline = timeout.map(|t| Instant::now() + t);
+    let mut prev = Instant::now();
     let mut seen_a = false;
     loop {
-        match solve_rx.recv() {
-            Ok(p) => {
-                let dur = {
-                    let mut t = time.write().unwrap();
-                    let e = t.elapsed();
-                    *t = Instant::now();
-                    e
-                };
-                match &p {
-                    Part::A(_) | Part::Both(_, _) => seen_a = true,
-                    Part::B(_) if !seen_a => {
-                        panic!("Part B can't be answered before part A. Undo the shenanigans.")
-                    }
-                    _ => {}
-                }
-                print_tx.send((p, dur)).unwrap()
+        let received = match deadline {
+            Some(d) => solve_rx.recv_timeout(d.saturating_duration_since(Instant::now())),
+            None => solve_rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        let p = match received {
+            Ok(p) => p,
+            Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => {
+                // keep draining, so the abandoned solver doesn't panic on send
+                thread::spawn(move || solve_rx.into_iter().for_each(drop));
+                return (Outcome::Timeout(timeout.unwrap()), Vec::new());
             }
-            Err(RecvError) => break,
+        };
+        if let Part::Progress { .. } = p {
+            print_tx.send((p, prev.elapsed())).unwrap();
+            continue;
         }
+        let dur = prev.elapsed();
+        prev = Instant::now();
+        match &p {
+            Part::A(_) | Part::Both(_, _) => seen_a = true,
+            Part::B(_) if !seen_a => {
+                thread::spawn(move || solve_rx.into_iter().for_each(drop));
+                let msg = "Part B can't be answered before part A. Undo the shenanigans.";
+                return (Outcome::Panicked(msg.to_string()), Vec::new());
+            }
+            _ => {}
+        }
+        print_tx.send((p, dur)).unwrap()
     }
+    let (solve_elapsed, nodes) = match worker.join() {
+        Ok(Ok(solved)) => solved,
+        Ok(Err(msg)) => return (Outcome::Panicked(msg), Vec::new()),
+        Err(payload) => return (Outcome::Panicked(panic_message(&*payload)), Vec::new()),
+    };
+    print_tx.send((Part::Join(), solve_elapsed)).unwrap();
+    (Outcome::Completed, nodes)
 }
 
-fn submit(year: u32, day: u8, part: &str, val: &str) -> bool {
-    if aocd::submit_answer(year, day, part, val)
-        .expect("Answer should submit without error, valid or not.")
-    {
-        println!("{}", style(format!("Verified {part:?}")).green());
-        true
+fn thread_name(year: u32, day: u8) -> String {
+    format!("solve-{year}-{day:02}")
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
     } else {
-        println!("{}", style(format!("Failed {part:?}")).red());
-        false
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Checks an answer against the local [Answers] store, falling back to
+/// submitting it via `aocd` if it isn't known and `mode` allows it. Answers
+/// confirmed correct upstream are recorded locally for next time. If `aocd`
+/// can't give a verdict, the answer is left unverified.
+fn verify(answers: &mut Answers, mode: Mode, year: u32, day: u8, part: &str, val: &str) -> Verdict {
+    match answers.check(year, day, part, val) {
+        Verdict::Unverified if mode == Mode::Submit => {
+            match aocd::submit_answer(year, day, part, val) {
+                Ok(()) => {
+                    if let Err(e) = answers.record(year, day, part, val) {
+                        eprintln!("{}", style(format!("Failed to record {part:?}: {e}")).red());
+                    }
+                    Verdict::Correct
+                }
+                Err(AocdError::WrongAnswer { expected }) => Verdict::Wrong(expected),
+                Err(e @ AocdError::RateLimited) => {
+                    eprintln!(
+                        "{}",
+                        style(format!("{e} before verifying {part:?}")).yellow()
+                    );
+                    Verdict::Unverified
+                }
+                Err(e @ AocdError::ExpiredToken) => {
+                    eprintln!("{}", style(format!("{e}!")).red().bold());
+                    Verdict::Unverified
+                }
+                Err(e) => {
+                    eprintln!("{}", style(format!("Couldn't submit {part:?}: {e}")).red());
+                    Verdict::Unverified
+                }
+            }
+        }
+        v => v,
     }
 }
 
 struct Print {
+    answers: Answers,
+    mode: Mode,
     correct_style: Style,
     wrong_style: Style,
+    unverified_style: Style,
     ans_style: Style,
     parse_style: Style,
     other_style: Style,
     time_style: Style,
+    progress_style: Style,
     ans_count: AtomicUsize,
+    term: Term,
+    /// When the progress bar was last drawn, if it's currently shown.
+    progress_drawn: Option<Instant>,
+}
+
+impl Drop for Print {
+    fn drop(&mut self) {
+        self.clear_progress();
+    }
 }
 
 impl Print {
-    fn new() -> Print {
+    fn new(answers: Answers) -> Print {
         Print {
+            answers,
+            mode: Mode::from_env(),
             correct_style: Style::new().on_green(),
             wrong_style: Style::new().on_red(),
+            unverified_style: Style::new().on_magenta(),
             parse_style: Style::new().on_blue(),
             other_style: Style::new().on_yellow(),
             ans_style: Style::new().underlined(),
             time_style: Style::new().dim(),
+            progress_style: Style::new().cyan(),
             ans_count: AtomicUsize::new(1),
+            term: Term::stdout(),
+            progress_drawn: None,
+        }
+    }
+
+    fn part_style(&self, verdict: &Verdict) -> &Style {
+        match verdict {
+            Verdict::Correct => &self.correct_style,
+            Verdict::Wrong(_) => &self.wrong_style,
+            Verdict::Unverified => &self.unverified_style,
+        }
+    }
+
+    fn verify(&mut self, year: u32, day: u8, part: &str, val: &str) -> Verdict {
+        let verdict = verify(&mut self.answers, self.mode, year, day, part, val);
+        match &verdict {
+            Verdict::Correct => println!("{}", style(format!("Verified {part:?}")).green()),
+            Verdict::Wrong(Some(ans)) => println!(
+                "{}",
+                style(format!("Failed {part:?}, expected '{ans}'")).red()
+            ),
+            Verdict::Wrong(None) => println!("{}", style(format!("Failed {part:?}")).red()),
+            Verdict::Unverified => println!("{}", style(format!("Unverified {part:?}")).yellow()),
         }
+        verdict
     }
 
-    fn part_style(&self, correct: bool) -> &Style {
-        if correct {
-            &self.correct_style
+    fn print_part(
+        &mut self,
+        json_events: bool,
+        year: u32,
+        day: u8,
+        part: &Part,
+        duration: Duration,
+    ) -> bool {
+        if json_events {
+            self.print_json(year, day, part, duration)
         } else {
-            &self.wrong_style
+            self.print(year, day, part, duration)
+        }
+    }
+
+    fn print_json(&mut self, year: u32, day: u8, part: &Part, duration: Duration) -> bool {
+        let mut verdict = |p, val| verify(&mut self.answers, self.mode, year, day, p, val);
+        let verdicts = match part {
+            Part::Progress { .. } => return true,
+            Part::A(a) => vec![verdict("a", a)],
+            Part::B(b) => vec![verdict("b", b)],
+            Part::Both(a, b) => vec![verdict("a", a), verdict("b", b)],
+            _ => vec![],
+        };
+        let mut obj = part.json_value(duration);
+        match verdicts.as_slice() {
+            [] => {}
+            [v] => obj["verdict"] = json!(v.name()),
+            vs => obj["verdict"] = vs.iter().map(|v| v.name()).collect(),
         }
+        println!("{obj}");
+        verdicts.iter().all(Verdict::is_ok)
     }
 
-    fn print(&self, year: u32, day: u8, part: &Part, duration: Duration) -> bool {
+    fn print(&mut self, year: u32, day: u8, part: &Part, duration: Duration) -> bool {
+        if let Part::Progress { done, total, label } = part {
+            self.print_progress(*done, *total, label);
+            return true;
+        }
+        self.clear_progress();
         let count = self.ans_count.load(Ordering::SeqCst);
         let mut correct = true;
         match part {
             Part::A(a) => {
-                correct &= submit(year, day, "a", a);
+                let v = self.verify(year, day, "a", a);
+                correct &= v.is_ok();
                 self.do_print(
-                    self.part_style(correct).apply_to("Part A:".to_string()),
+                    self.part_style(&v).apply_to("Part A:".to_string()),
                     Some(a),
                     Some(duration),
                 )
             }
             Part::B(b) => {
-                correct &= submit(year, day, "b", b);
+                let v = self.verify(year, day, "b", b);
+                correct &= v.is_ok();
                 self.do_print(
-                    self.part_style(correct).apply_to("Part B:".to_string()),
+                    self.part_style(&v).apply_to("Part B:".to_string()),
                     Some(b),
                     Some(duration),
                 )
             }
             Part::Both(a, b) => {
-                let ac = submit(year, day, "a", a);
-                let bc = submit(year, day, "b", b);
-                correct &= ac & bc;
+                let av = self.verify(year, day, "a", a);
+                let bv = self.verify(year, day, "b", b);
+                correct &= av.is_ok() & bv.is_ok();
                 self.do_print(
-                    self.part_style(ac).apply_to("Part A:".to_string()),
+                    self.part_style(&av).apply_to("Part A:".to_string()),
                     Some(a),
                     None,
                 );
                 self.do_print(
-                    self.part_style(bc).apply_to("Part B:".to_string()),
+                    self.part_style(&bv).apply_to("Part B:".to_string()),
                     Some(b),
                     Some(duration),
                 )
@@ -230,10 +579,46 @@ impl Print {
                 None,
                 Some(duration),
             ),
+            Part::Progress { .. } => unreachable!(),
         }
         correct
     }
 
+    /// Redraws the progress bar in place, unless it was drawn very recently,
+    /// or stdout isn't a terminal.
+    fn print_progress(&mut self, done: usize, total: usize, label: &str) {
+        if !self.term.is_term() {
+            return;
+        }
+        if done < total
+            && self
+                .progress_drawn
+                .is_some_and(|at| at.elapsed() < PROGRESS_INTERVAL)
+        {
+            return;
+        }
+        const WIDTH: usize = 40;
+        let filled = (done.min(total) * WIDTH)
+            .checked_div(total)
+            .unwrap_or(WIDTH);
+        let line = format!(
+            "{:>11} [{}{}] {done}/{total}",
+            label,
+            self.progress_style.apply_to("#".repeat(filled)),
+            self.time_style.apply_to("-".repeat(WIDTH - filled)),
+        );
+        // a failure to draw progress isn't worth failing over
+        let _ = self.term.clear_line();
+        let _ = self.term.write_str(&line);
+        self.progress_drawn = Some(Instant::now());
+    }
+
+    fn clear_progress(&mut self) {
+        if self.progress_drawn.take().is_some() {
+            let _ = self.term.clear_line();
+        }
+    }
+
     fn do_print(
         &self,
         lbl: StyledObject<String>,
@@ -275,3 +660,127 @@ impl Print {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::mpsc::Receiver;
+
+    fn run<S>(work: S, timeout: Option<Duration>) -> (Outcome, Vec<String>)
+    where
+        S: FnOnce(&str, Sender<Part>) + Send + 'static,
+    {
+        let (tx, rx): (_, Receiver<(Part, Duration)>) = channel();
+        let (outcome, _) = solve_timed("test".to_string(), "input\n", work, tx, timeout);
+        let kinds = rx.into_iter().map(|(p, _)| p.kind().to_string()).collect();
+        (outcome, kinds)
+    }
+
+    #[test]
+    fn completed() {
+        let (outcome, kinds) = run(
+            |input, tx| {
+                assert_eq!("input", input);
+                tx.send(Part::A("1".to_string())).unwrap();
+                tx.send(Part::B("2".to_string())).unwrap();
+            },
+            Some(Duration::from_secs(10)),
+        );
+        assert_eq!(Outcome::Completed, outcome);
+        assert_eq!(vec!["a", "b", "join"], kinds);
+    }
+
+    #[test]
+    fn deep_recursion() {
+        fn depth(n: usize) -> usize {
+            // enough stack per frame that 100k frames would overflow 8 MiB
+            let pad = std::hint::black_box([0u8; 128]);
+            if n == 0 {
+                pad[0] as usize
+            } else {
+                1 + depth(n - 1) + pad[n % 128] as usize
+            }
+        }
+        let (outcome, kinds) = run(
+            |_, tx| tx.send(Part::A(depth(100_000).to_string())).unwrap(),
+            None,
+        );
+        assert_eq!(Outcome::Completed, outcome);
+        assert_eq!(vec!["a", "join"], kinds);
+    }
+
+    #[test]
+    fn join_duration() {
+        let (tx, rx) = channel();
+        let work = |_: &str, _: Sender<Part>| thread::sleep(Duration::from_millis(10));
+        solve_timed("test".to_string(), "", work, tx, None);
+        let parts: Vec<_> = rx.into_iter().collect();
+        assert!(matches!(parts[..], [(Part::Join(), _)]));
+        assert!(parts[0].1 >= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn profiled() {
+        let (tx, _rx) = channel();
+        let work = |_: &str, _: Sender<Part>| profile::scope("profiled", || {});
+        let (outcome, nodes) = solve_timed("test".to_string(), "", work, tx, None);
+        assert_eq!(Outcome::Completed, outcome);
+        assert_eq!(1, nodes.len());
+        assert_eq!(vec!["profiled"], nodes[0].path);
+    }
+
+    #[test]
+    fn progress() {
+        let (outcome, kinds) = run(
+            |_, tx| {
+                for done in 0..3 {
+                    tx.send(Part::Progress {
+                        done,
+                        total: 3,
+                        label: "a".to_string(),
+                    })
+                    .unwrap();
+                }
+                tx.send(Part::A("1".to_string())).unwrap();
+            },
+            None,
+        );
+        assert_eq!(Outcome::Completed, outcome);
+        assert_eq!(vec!["progress", "progress", "progress", "a", "join"], kinds);
+    }
+
+    #[test]
+    fn panicked() {
+        let (outcome, kinds) = run(
+            |_, tx| {
+                tx.send(Part::A("1".to_string())).unwrap();
+                panic!("no part {}", 'b');
+            },
+            None,
+        );
+        assert_eq!(Outcome::Panicked("no part b".to_string()), outcome);
+        assert_eq!(vec!["a"], kinds);
+    }
+
+    #[test]
+    fn b_before_a() {
+        let (outcome, kinds) = run(|_, tx| tx.send(Part::B("2".to_string())).unwrap(), None);
+        assert!(matches!(outcome, Outcome::Panicked(_)));
+        assert!(kinds.is_empty());
+    }
+
+    #[test]
+    fn timeout() {
+        let limit = Duration::from_millis(50);
+        let (outcome, kinds) = run(
+            |_, tx| {
+                tx.send(Part::A("1".to_string())).unwrap();
+                thread::sleep(Duration::from_secs(1));
+                tx.send(Part::B("2".to_string())).unwrap();
+            },
+            Some(limit),
+        );
+        assert_eq!(Outcome::Timeout(limit), outcome);
+        assert_eq!(vec!["a"], kinds);
+    }
+}
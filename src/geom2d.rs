@@ -1,7 +1,15 @@
+//! Two-dimensional geometry: the four cardinal [Dir]ections, eight-way
+//! [Dir8]s, and [Point2]s to move around with them. Coordinates are
+//! screen-style, with `y` increasing to the [South]. See [hex] for hexagonal
+//! grids.
+use std::error::Error;
 use std::fmt::{Display, Formatter, Write};
-use std::ops::{Add, Sub};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 use Dir::*;
 
+pub mod hex;
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Dir {
     North,
@@ -40,28 +48,96 @@ impl Dir {
     }
 }
 
-impl From<&str> for Dir {
-    fn from(value: &str) -> Self {
-        if let Some(c) = value.chars().next() {
-            c.into()
-        } else {
-            panic!("Can't convert an empty string to a Dir?!")
+/// Interprets a single character, either `U`/`R`/`D`/`L` or `^`/`>`/`v`/`<`.
+impl TryFrom<char> for Dir {
+    type Error = ParseDirError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | '^' => Ok(North),
+            'R' | '>' => Ok(East),
+            'D' | 'v' => Ok(South),
+            'L' | '<' => Ok(West),
+            c => Err(ParseDirError::BadChar { c, at: 0 }),
         }
     }
 }
 
-impl From<char> for Dir {
-    fn from(value: char) -> Self {
-        match value {
-            'U' | '^' => North,
-            'R' | '>' => East,
-            'D' | 'v' => South,
-            'L' | '<' => West,
-            c => panic!("Can't interpret '{c}' as a direction?!"),
+/// Interprets a string of exactly one character, as for `TryFrom<char>`.
+impl FromStr for Dir {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Err(ParseDirError::Empty),
+            (Some(c), None) => c.try_into(),
+            (Some(_), Some(c)) => Err(ParseDirError::BadChar { c, at: 1 }),
+        }
+    }
+}
+
+impl TryFrom<&str> for Dir {
+    type Error = ParseDirError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Interprets every character of a move string like `<^^>v` as a [Dir],
+/// ignoring whitespace, so moves may span lines.
+///
+/// ```
+/// # use aoc::geom2d::{parse_moves, Dir::*, ParseDirError};
+/// assert_eq!(Ok(vec![West, North, North, East, South]), parse_moves("<^^>v"));
+/// assert_eq!(
+///     Err(ParseDirError::BadChar { c: 'x', at: 3 }),
+///     parse_moves("<^\nx")
+/// );
+/// ```
+pub fn parse_moves(s: &str) -> Result<Vec<Dir>, ParseDirError> {
+    s.chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| Dir::try_from(c).map_err(|e| e.offset(i)))
+        .collect()
+}
+
+/// A [Dir], [Dir8], or hex direction couldn't be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseDirError {
+    /// There was nothing to parse.
+    Empty,
+    /// A character wasn't a direction, at the given (`char`) position.
+    BadChar { c: char, at: usize },
+    /// A name wasn't one of the directions.
+    BadName(String),
+}
+
+impl ParseDirError {
+    fn offset(self, by: usize) -> ParseDirError {
+        match self {
+            ParseDirError::BadChar { c, at } => ParseDirError::BadChar { c, at: at + by },
+            e => e,
+        }
+    }
+}
+
+impl Display for ParseDirError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDirError::Empty => write!(f, "Can't interpret an empty string as a direction"),
+            ParseDirError::BadChar { c, at } => {
+                write!(f, "Can't interpret '{c}' at {at} as a direction")
+            }
+            ParseDirError::BadName(name) => write!(f, "Can't interpret '{name}' as a direction"),
         }
     }
 }
 
+impl Error for ParseDirError {}
+
 impl Display for Dir {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -73,6 +149,152 @@ impl Display for Dir {
     }
 }
 
+/// The four cardinal directions, and the four diagonals between them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from [North](Dir8::North).
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    fn turn(&self, eighths: usize) -> Dir8 {
+        Dir8::ALL[(*self as usize + eighths) % 8]
+    }
+
+    /// Turns 45° clockwise.
+    pub fn turn_right_45(&self) -> Dir8 {
+        self.turn(1)
+    }
+
+    /// Turns 45° counterclockwise.
+    pub fn turn_left_45(&self) -> Dir8 {
+        self.turn(7)
+    }
+
+    pub fn turn_right(&self) -> Dir8 {
+        self.turn(2)
+    }
+
+    pub fn turn_left(&self) -> Dir8 {
+        self.turn(6)
+    }
+
+    pub fn turn_around(&self) -> Dir8 {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    /// The equivalent [Dir], if this isn't a diagonal.
+    pub fn to_dir(&self) -> Option<Dir> {
+        match self {
+            Dir8::North => Some(North),
+            Dir8::East => Some(East),
+            Dir8::South => Some(South),
+            Dir8::West => Some(West),
+            _ => None,
+        }
+    }
+
+    /// The `(dx, dy)` of a single step in this direction.
+    #[rustfmt::skip]
+    pub fn offset(&self) -> (i8, i8) {
+        match self {
+            Dir8::North     => ( 0, -1),
+            Dir8::NorthEast => ( 1, -1),
+            Dir8::East      => ( 1,  0),
+            Dir8::SouthEast => ( 1,  1),
+            Dir8::South     => ( 0,  1),
+            Dir8::SouthWest => (-1,  1),
+            Dir8::West      => (-1,  0),
+            Dir8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(value: Dir) -> Self {
+        match value {
+            North => Dir8::North,
+            East => Dir8::East,
+            South => Dir8::South,
+            West => Dir8::West,
+        }
+    }
+}
+
+/// Accepts compass points (`N`, `NE`, etc.), full names (`northeast` or
+/// `north-east`), and the [Dir] notations (`U`, `>`, etc.), in any case.
+impl FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize(s).as_str() {
+            "" => Err(ParseDirError::Empty),
+            "N" | "NORTH" | "U" | "^" => Ok(Dir8::North),
+            "NE" | "NORTHEAST" => Ok(Dir8::NorthEast),
+            "E" | "EAST" | "R" | ">" => Ok(Dir8::East),
+            "SE" | "SOUTHEAST" => Ok(Dir8::SouthEast),
+            "S" | "SOUTH" | "D" | "V" => Ok(Dir8::South),
+            "SW" | "SOUTHWEST" => Ok(Dir8::SouthWest),
+            "W" | "WEST" | "L" | "<" => Ok(Dir8::West),
+            "NW" | "NORTHWEST" => Ok(Dir8::NorthWest),
+            _ => Err(ParseDirError::BadName(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Dir8 {
+    type Error = ParseDirError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Uppercases a direction's name, and drops any separators.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+impl Display for Dir8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Dir8::North => "N",
+            Dir8::NorthEast => "NE",
+            Dir8::East => "E",
+            Dir8::SouthEast => "SE",
+            Dir8::South => "S",
+            Dir8::SouthWest => "SW",
+            Dir8::West => "W",
+            Dir8::NorthWest => "NW",
+        })
+    }
+}
+
 pub fn step<T>(p: (T, T), d: Dir) -> (T, T)
 where
     T: Add<Output = T> + Copy + From<u8> + Sub<Output = T>,
@@ -94,16 +316,286 @@ where
     }
 }
 
+/// A point (or vector) in two dimensions.
+///
+/// ```
+/// # use aoc::geom2d::{Dir, Point2};
+/// let p = Point2::new(3, -1);
+///
+/// assert_eq!(Point2::new(5, -3), p + Point2::new(2, -2));
+/// assert_eq!(Point2::new(3, -2), p.step(Dir::North));
+/// assert_eq!(Point2::new(1, 3), p.rotate_right());
+/// assert_eq!(4, p.manhattan_distance(Point2::default()));
+/// assert_eq!((3, -1), p.into());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point2<T>
+where
+    T: Add<Output = T> + Copy + PartialOrd + Sub<Output = T>,
+{
+    /// The sum of the absolute differences of the coordinates. Safe for
+    /// unsigned coordinates, too.
+    pub fn manhattan_distance(&self, other: Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The largest absolute difference of the coordinates, i.e., the number of
+    /// king's moves between the points. Safe for unsigned coordinates, too.
+    pub fn chebyshev_distance(&self, other: Point2<T>) -> T {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        if dx < dy {
+            dy
+        } else {
+            dx
+        }
+    }
+}
+
+fn abs_diff<T>(a: T, b: T) -> T
+where
+    T: PartialOrd + Sub<Output = T>,
+{
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+impl<T> Point2<T>
+where
+    T: Add<Output = T> + Copy + Sub<Output = T>,
+{
+    /// The point one unit away in the passed direction.
+    pub fn step(self, d: Dir) -> Point2<T>
+    where
+        T: From<u8>,
+    {
+        step(self.into(), d).into()
+    }
+
+    /// The point `n` units away in the passed direction.
+    pub fn step_by(self, d: Dir, n: T) -> Point2<T> {
+        step_by(self.into(), d, n).into()
+    }
+}
+
+impl<T> Point2<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    /// Rotates 90° clockwise about the origin, as [Dir::turn_right] does.
+    pub fn rotate_right(self) -> Point2<T> {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Rotates 90° counterclockwise about the origin, as [Dir::turn_left] does.
+    pub fn rotate_left(self) -> Point2<T> {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// Rotates 180° about the origin.
+    pub fn rotate_around(self) -> Point2<T> {
+        -self
+    }
+
+    /// Rotates clockwise about the origin by the passed number of 90° turns,
+    /// which may be negative for counterclockwise.
+    pub fn rotate(self, quarter_turns: i32) -> Point2<T> {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => self.rotate_right(),
+            2 => self.rotate_around(),
+            _ => self.rotate_left(),
+        }
+    }
+}
+
+impl<T> Point2<T>
+where
+    T: Copy + Ord,
+{
+    /// The smallest point whose coordinates are at least both points'.
+    pub fn component_max(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// The largest point whose coordinates are at most both points'.
+    pub fn component_min(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Whether this point is within the box with the passed (inclusive)
+    /// corners, as from [bounding_box].
+    pub fn is_within(&self, min: Point2<T>, max: Point2<T>) -> bool {
+        min.x <= self.x && self.x <= max.x && min.y <= self.y && self.y <= max.y
+    }
+}
+
+/// The smallest box containing every passed point, as its minimum and maximum
+/// (inclusive) corners, or `None` if there are no points.
+///
+/// ```
+/// # use aoc::geom2d::{bounding_box, Point2};
+/// let pts = [(3, 1), (-2, 4), (0, 0)].map(Point2::from);
+///
+/// assert_eq!(
+///     Some((Point2::new(-2, 0), Point2::new(3, 4))),
+///     bounding_box(pts)
+/// );
+/// ```
+pub fn bounding_box<T, I>(points: I) -> Option<(Point2<T>, Point2<T>)>
+where
+    T: Copy + Ord,
+    I: IntoIterator<Item = Point2<T>>,
+{
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    Some(points.fold((first, first), |(min, max), p| {
+        (min.component_min(p), max.component_max(p))
+    }))
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+/// The unit vector in the passed direction.
+impl<T> From<Dir> for Point2<T>
+where
+    T: From<i8>,
+{
+    fn from(d: Dir) -> Self {
+        let (x, y) = match d {
+            North => (0, -1),
+            East => (1, 0),
+            South => (0, 1),
+            West => (-1, 0),
+        };
+        Point2::new(x.into(), y.into())
+    }
+}
+
+/// The unit (or diagonal) vector in the passed direction.
+impl<T> From<Dir8> for Point2<T>
+where
+    T: From<i8>,
+{
+    fn from(d: Dir8) -> Self {
+        let (x, y) = d.offset();
+        Point2::new(x.into(), y.into())
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Self::Output {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Point2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Copy + MulAssign> MulAssign<T> for Point2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     #[test]
     fn from_str() {
-        assert_eq!(North, "U".into());
-        assert_eq!(East, "R".into());
-        assert_eq!(South, "D".into());
-        assert_eq!(West, "L".into());
+        assert_eq!(Ok(North), "U".parse());
+        assert_eq!(Ok(East), "R".parse());
+        assert_eq!(Ok(South), "D".try_into());
+        assert_eq!(Ok(West), Dir::try_from('<'));
+        for d in [North, East, South, West] {
+            assert_eq!(Ok(d), d.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn from_bad_str() {
+        assert_eq!(Err(ParseDirError::Empty), "".parse::<Dir>());
+        assert_eq!(
+            Err(ParseDirError::BadChar { c: 'x', at: 0 }),
+            Dir::try_from('x')
+        );
+        assert_eq!(
+            Err(ParseDirError::BadChar { c: ' ', at: 1 }),
+            "R 4".parse::<Dir>()
+        );
+        let e = parse_moves("<^^>\nv>?<").unwrap_err();
+        assert_eq!(ParseDirError::BadChar { c: '?', at: 7 }, e);
+        assert_eq!("Can't interpret '?' at 7 as a direction", e.to_string());
+        assert_eq!(Ok(vec![]), parse_moves(""));
     }
 
     #[test]
@@ -121,4 +613,99 @@ mod test {
         assert_eq!(North, South.turn_around());
         assert_eq!(East, West.turn_around());
     }
+
+    #[test]
+    fn point_arithmetic() {
+        let mut p = Point2::new(1, 2);
+        p += Point2::new(3, 4);
+        assert_eq!(Point2::new(4, 6), p);
+        p -= Point2::new(1, 1);
+        assert_eq!(Point2::new(3, 5), p);
+        assert_eq!(Point2::new(-3, -5), -p);
+        assert_eq!(Point2::new(6, 10), p * 2);
+        p *= 3;
+        assert_eq!(Point2::new(9, 15), p);
+        assert_eq!(Point2::new(8, 13), p - Point2::new(1, 2));
+    }
+
+    #[test]
+    fn point_distances() {
+        let (a, b) = (Point2::new(1, 5), Point2::new(4, 1));
+        assert_eq!(7, a.manhattan_distance(b));
+        assert_eq!(4, a.chebyshev_distance(b));
+        let (a, b) = (Point2::new(1usize, 5), Point2::new(4, 1));
+        assert_eq!(7, b.manhattan_distance(a));
+        assert_eq!(4, b.chebyshev_distance(a));
+    }
+
+    #[test]
+    fn point_rotations_match_turns() {
+        for d in [North, East, South, West] {
+            let p: Point2<i32> = d.into();
+            assert_eq!(Point2::from(d.turn_right()), p.rotate_right());
+            assert_eq!(Point2::from(d.turn_left()), p.rotate_left());
+            assert_eq!(Point2::from(d.turn_around()), p.rotate_around());
+            assert_eq!(p.rotate_left(), p.rotate(-1));
+            assert_eq!(p.rotate_left(), p.rotate(7));
+            assert_eq!(p, p.rotate(4));
+            assert_eq!(p + p, p.step(d));
+        }
+        let p = Point2::new(2, 3);
+        assert_eq!(Point2::new(-3, 2), p.rotate_right());
+        assert_eq!(Point2::new(3, -2), p.rotate_left());
+        assert_eq!(Point2::new(2, 8), p.step_by(South, 5));
+    }
+
+    #[test]
+    fn point_bounds() {
+        assert_eq!(None, bounding_box(Vec::<Point2<u8>>::new()));
+        let pts = [(5, 1), (2, 7), (3, 3)].map(Point2::from);
+        let (min, max) = bounding_box(pts).unwrap();
+        assert_eq!((Point2::new(2, 1), Point2::new(5, 7)), (min, max));
+        assert!(pts.iter().all(|p| p.is_within(min, max)));
+        assert!(!Point2::new(1, 1).is_within(min, max));
+        assert_eq!("(2, 1)", min.to_string());
+        // unlike Ord's, which compare x first
+        let (a, b) = (Point2::new(5, 1), Point2::new(2, 7));
+        assert_eq!(Point2::new(5, 7), a.component_max(b));
+        assert_eq!(Point2::new(2, 1), a.component_min(b));
+        assert_eq!(a, a.max(b));
+    }
+
+    #[test]
+    fn dir8_from_str() {
+        assert_eq!(Ok(Dir8::North), "N".parse());
+        assert_eq!(Ok(Dir8::North), "u".parse());
+        assert_eq!(Ok(Dir8::NorthEast), "NE".parse());
+        assert_eq!(Ok(Dir8::NorthWest), "nw".parse());
+        assert_eq!(Ok(Dir8::SouthEast), Dir8::try_from("south-east"));
+        assert_eq!(Ok(Dir8::West), "<".parse());
+        for d in Dir8::ALL {
+            assert_eq!(Ok(d), d.to_string().parse());
+        }
+        assert_eq!(
+            Err(ParseDirError::BadName("NNE".to_string())),
+            "NNE".parse::<Dir8>()
+        );
+        assert_eq!(Err(ParseDirError::Empty), "-".parse::<Dir8>());
+    }
+
+    #[test]
+    fn dir8_turns() {
+        use Dir8 as D;
+        assert_eq!(D::NorthEast, D::North.turn_right_45());
+        assert_eq!(D::NorthWest, D::North.turn_left_45());
+        assert_eq!(D::SouthWest, D::NorthWest.turn_left());
+        assert_eq!(D::NorthEast, D::SouthWest.turn_around());
+        for d in [North, East, South, West] {
+            let d8 = Dir8::from(d);
+            assert!(!d8.is_diagonal());
+            assert!(d8.turn_right_45().is_diagonal());
+            assert_eq!(Some(d.turn_right()), d8.turn_right().to_dir());
+            assert_eq!(Some(d.turn_left()), d8.turn_left().to_dir());
+            assert_eq!(Point2::<i32>::from(d), d8.into());
+        }
+        let diagonal: Point2<i32> = D::SouthWest.into();
+        assert_eq!(Point2::from(South) + Point2::from(West), diagonal);
+    }
 }
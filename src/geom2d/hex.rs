@@ -0,0 +1,375 @@
+//! Hexagonal grids, in axial coordinates. Each [Hex] has a `q` and an `r`, and
+//! an implied third cube coordinate `s`, so that `q + r + s == 0`. Both grid
+//! orientations share the coordinates: the six directions from a flat-topped
+//! hex are [FlatDir]s, and from a pointy-topped hex are [PointyDir]s.
+//!
+//! ```
+//! # use aoc::geom2d::hex::{FlatDir, Hex};
+//! let end = "ne,ne,s,s"
+//!     .split(',')
+//!     .map(|d| d.parse::<FlatDir>().unwrap())
+//!     .fold(Hex::ORIGIN, Hex::step);
+//! assert_eq!(2, end.distance(Hex::ORIGIN));
+//! ```
+use super::{normalize, ParseDirError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A hex, in axial coordinates.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    pub const fn new(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    /// Builds a hex from cube coordinates, which must sum to zero.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Hex {
+        assert_eq!(0, q + r + s, "Cube coordinates must sum to zero");
+        Hex::new(q, r)
+    }
+
+    /// The implied third cube coordinate.
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// This hex's cube coordinates, as `(q, r, s)`.
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    /// The number of steps between two hexes.
+    pub fn distance(&self, other: Hex) -> i32 {
+        let (q, r, s) = (*self - other).cube();
+        (q.abs() + r.abs() + s.abs()) / 2
+    }
+
+    /// The adjacent hex in the passed direction, of either orientation.
+    pub fn step<D: Into<Hex>>(self, d: D) -> Hex {
+        self + d.into()
+    }
+
+    /// The six adjacent hexes, clockwise from the one in [FlatDir::North].
+    pub fn neighbors(&self) -> impl Iterator<Item = Hex> + '_ {
+        FlatDir::ALL.into_iter().map(|d| self.step(d))
+    }
+}
+
+impl From<(i32, i32)> for Hex {
+    fn from((q, r): (i32, i32)) -> Self {
+        Hex::new(q, r)
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Self::Output {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+/// The six directions from a flat-topped hex, which has neighbors directly
+/// north and south.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FlatDir {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl FlatDir {
+    /// Every direction, clockwise from [North](FlatDir::North).
+    pub const ALL: [FlatDir; 6] = [
+        FlatDir::North,
+        FlatDir::NorthEast,
+        FlatDir::SouthEast,
+        FlatDir::South,
+        FlatDir::SouthWest,
+        FlatDir::NorthWest,
+    ];
+
+    fn turn(&self, sixths: usize) -> FlatDir {
+        FlatDir::ALL[(*self as usize + sixths) % 6]
+    }
+
+    /// Turns 60° clockwise.
+    pub fn turn_right(&self) -> FlatDir {
+        self.turn(1)
+    }
+
+    /// Turns 60° counterclockwise.
+    pub fn turn_left(&self) -> FlatDir {
+        self.turn(5)
+    }
+
+    pub fn turn_around(&self) -> FlatDir {
+        self.turn(3)
+    }
+}
+
+/// The unit vector in the passed direction.
+impl From<FlatDir> for Hex {
+    fn from(d: FlatDir) -> Self {
+        match d {
+            FlatDir::North => Hex::new(0, -1),
+            FlatDir::NorthEast => Hex::new(1, -1),
+            FlatDir::SouthEast => Hex::new(1, 0),
+            FlatDir::South => Hex::new(0, 1),
+            FlatDir::SouthWest => Hex::new(-1, 1),
+            FlatDir::NorthWest => Hex::new(-1, 0),
+        }
+    }
+}
+
+/// Accepts compass points (`N`, `NE`, etc.), full names (`northeast` or
+/// `north-east`), and `U`/`D`, in any case.
+impl FromStr for FlatDir {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize(s).as_str() {
+            "" => Err(ParseDirError::Empty),
+            "N" | "NORTH" | "U" => Ok(FlatDir::North),
+            "NE" | "NORTHEAST" => Ok(FlatDir::NorthEast),
+            "SE" | "SOUTHEAST" => Ok(FlatDir::SouthEast),
+            "S" | "SOUTH" | "D" => Ok(FlatDir::South),
+            "SW" | "SOUTHWEST" => Ok(FlatDir::SouthWest),
+            "NW" | "NORTHWEST" => Ok(FlatDir::NorthWest),
+            _ => Err(ParseDirError::BadName(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for FlatDir {
+    type Error = ParseDirError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for FlatDir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FlatDir::North => "N",
+            FlatDir::NorthEast => "NE",
+            FlatDir::SouthEast => "SE",
+            FlatDir::South => "S",
+            FlatDir::SouthWest => "SW",
+            FlatDir::NorthWest => "NW",
+        })
+    }
+}
+
+/// The six directions from a pointy-topped hex, which has neighbors directly
+/// east and west.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PointyDir {
+    NorthEast,
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl PointyDir {
+    /// Every direction, clockwise from [NorthEast](PointyDir::NorthEast).
+    pub const ALL: [PointyDir; 6] = [
+        PointyDir::NorthEast,
+        PointyDir::East,
+        PointyDir::SouthEast,
+        PointyDir::SouthWest,
+        PointyDir::West,
+        PointyDir::NorthWest,
+    ];
+
+    fn turn(&self, sixths: usize) -> PointyDir {
+        PointyDir::ALL[(*self as usize + sixths) % 6]
+    }
+
+    /// Turns 60° clockwise.
+    pub fn turn_right(&self) -> PointyDir {
+        self.turn(1)
+    }
+
+    /// Turns 60° counterclockwise.
+    pub fn turn_left(&self) -> PointyDir {
+        self.turn(5)
+    }
+
+    pub fn turn_around(&self) -> PointyDir {
+        self.turn(3)
+    }
+}
+
+/// The unit vector in the passed direction.
+impl From<PointyDir> for Hex {
+    fn from(d: PointyDir) -> Self {
+        match d {
+            PointyDir::NorthEast => Hex::new(1, -1),
+            PointyDir::East => Hex::new(1, 0),
+            PointyDir::SouthEast => Hex::new(0, 1),
+            PointyDir::SouthWest => Hex::new(-1, 1),
+            PointyDir::West => Hex::new(-1, 0),
+            PointyDir::NorthWest => Hex::new(0, -1),
+        }
+    }
+}
+
+/// Accepts compass points (`E`, `NE`, etc.), full names (`northeast` or
+/// `north-east`), and `L`/`R`, in any case.
+impl FromStr for PointyDir {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize(s).as_str() {
+            "" => Err(ParseDirError::Empty),
+            "NE" | "NORTHEAST" => Ok(PointyDir::NorthEast),
+            "E" | "EAST" | "R" => Ok(PointyDir::East),
+            "SE" | "SOUTHEAST" => Ok(PointyDir::SouthEast),
+            "SW" | "SOUTHWEST" => Ok(PointyDir::SouthWest),
+            "W" | "WEST" | "L" => Ok(PointyDir::West),
+            "NW" | "NORTHWEST" => Ok(PointyDir::NorthWest),
+            _ => Err(ParseDirError::BadName(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for PointyDir {
+    type Error = ParseDirError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for PointyDir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PointyDir::NorthEast => "NE",
+            PointyDir::East => "E",
+            PointyDir::SouthEast => "SE",
+            PointyDir::SouthWest => "SW",
+            PointyDir::West => "W",
+            PointyDir::NorthWest => "NW",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(FlatDir::North), "n".parse());
+        assert_eq!(Ok(FlatDir::North), "U".parse());
+        assert_eq!(Ok(FlatDir::SouthWest), FlatDir::try_from("sw"));
+        assert_eq!(Ok(PointyDir::East), "E".parse());
+        assert_eq!(Ok(PointyDir::West), "l".parse());
+        assert_eq!(Ok(PointyDir::NorthWest), "north-west".parse());
+        for d in FlatDir::ALL {
+            assert_eq!(Ok(d), d.to_string().parse());
+        }
+        for d in PointyDir::ALL {
+            assert_eq!(Ok(d), d.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn from_bad_str() {
+        let err = "N".parse::<PointyDir>().unwrap_err();
+        assert_eq!(ParseDirError::BadName("N".to_string()), err);
+        assert_eq!("Can't interpret 'N' as a direction", err.to_string());
+        assert_eq!(Err(ParseDirError::Empty), "".parse::<FlatDir>());
+    }
+
+    #[test]
+    fn turns() {
+        for d in FlatDir::ALL {
+            assert_eq!(d, d.turn_right().turn_left());
+            assert_eq!(-Hex::from(d), d.turn_around().into());
+        }
+        for d in PointyDir::ALL {
+            assert_eq!(d, d.turn_left().turn_right());
+            assert_eq!(-Hex::from(d), d.turn_around().into());
+        }
+        assert_eq!(FlatDir::NorthWest, FlatDir::North.turn_left());
+        assert_eq!(PointyDir::SouthEast, PointyDir::East.turn_right());
+    }
+
+    #[test]
+    fn distances() {
+        // hex_ed_11
+        let walk = |path: &str| {
+            path.split(',')
+                .map(|d| d.parse::<FlatDir>().unwrap())
+                .fold(Hex::ORIGIN, Hex::step)
+                .distance(Hex::ORIGIN)
+        };
+        assert_eq!(3, walk("ne,ne,ne"));
+        assert_eq!(0, walk("ne,ne,sw,sw"));
+        assert_eq!(2, walk("ne,ne,s,s"));
+        assert_eq!(3, walk("se,sw,se,sw,sw"));
+        // lobby_layout_24
+        let p = [PointyDir::NorthWest, PointyDir::West, PointyDir::SouthWest]
+            .into_iter()
+            .fold(Hex::ORIGIN, Hex::step);
+        assert_eq!(Hex::new(-2, 0), p);
+        assert_eq!((-2, 0, 2), p.cube());
+        assert_eq!(p, Hex::from_cube(-2, 0, 2));
+        for n in p.neighbors() {
+            assert_eq!(1, n.distance(p));
+        }
+        assert_eq!(6, p.neighbors().count());
+    }
+}
@@ -0,0 +1,5 @@
+//! Algorithms over graphs, generally [petgraph] ones, beyond what petgraph
+//! itself provides.
+pub mod contract;
+pub mod longest;
+pub mod undirected;
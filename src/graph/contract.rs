@@ -0,0 +1,225 @@
+//! Corridor contraction: reducing a maze-like graph to just its interesting
+//! nodes (forks, dead ends, starts and goals), with an edge for each corridor
+//! between them, weighted by its length.
+//!
+//! ```
+//! # use aoc::graph::contract::{contract_grid, Keep};
+//! # use aoc::grid::Grid;
+//! let grid: Grid<char> = "\
+//! █.███
+//! █...█
+//! █.█.█
+//! █...█
+//! ███.█"
+//!     .parse()
+//!     .unwrap();
+//! let mini = contract_grid(&grid, |&c| c != '█', Keep::Forks, false);
+//!
+//! // the ends, and the two forks on the loop between them
+//! assert_eq!(4, mini.graph.node_count());
+//! let fork = mini.index[&(1, 1)];
+//! let mut lens: Vec<_> = mini.graph.edges(fork).map(|e| e.weight().len).collect();
+//! lens.sort();
+//! assert_eq!(vec![1, 4, 4], lens);
+//! ```
+use crate::grid::{Grid, Pt};
+use petgraph::graph::NodeIndex;
+use petgraph::Graph;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Which nodes survive contraction.
+#[derive(Clone, Debug)]
+pub enum Keep<N> {
+    /// Nodes with other than two neighbors: forks and dead ends.
+    Forks,
+    /// Forks, and these nodes too, such as a start and goal in the middle of a
+    /// corridor.
+    ForksAnd(HashSet<N>),
+    /// Exactly these nodes. Contraction panics if a corridor between them
+    /// forks.
+    Only(HashSet<N>),
+}
+
+/// A corridor between two kept nodes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Corridor<N> {
+    /// The number of steps along the corridor.
+    pub len: usize,
+    /// Every node along the corridor, including both ends, if requested.
+    pub cells: Option<Vec<N>>,
+}
+
+/// A contracted graph, with an edge per (directed) corridor.
+#[derive(Clone, Debug)]
+pub struct Contracted<N> {
+    pub graph: Graph<N, Corridor<N>>,
+    /// The index of each kept node in the graph.
+    pub index: HashMap<N, NodeIndex>,
+}
+
+/// Contracts the graph described by an adjacency map of each node's
+/// successors. For an undirected graph, list each edge in both directions; its
+/// corridors will be too. A node's neighbors are both its successors and its
+/// predecessors. Corridors which dead-end in a one-way step are dropped. If
+/// `with_cells`, each [Corridor] records the nodes along it.
+pub fn contract<N>(adjacency: &HashMap<N, Vec<N>>, keep: Keep<N>, with_cells: bool) -> Contracted<N>
+where
+    N: Clone + Debug + Eq + Hash,
+{
+    let mut neighbors: HashMap<&N, HashSet<&N>> = HashMap::new();
+    for (n, succs) in adjacency {
+        neighbors.entry(n).or_default();
+        for s in succs {
+            neighbors.entry(n).or_default().insert(s);
+            neighbors.entry(s).or_default().insert(n);
+        }
+    }
+    let is_fork = |n: &N| neighbors[n].len() != 2;
+    let is_kept: HashSet<&N> = neighbors
+        .keys()
+        .copied()
+        .filter(|&n| match &keep {
+            Keep::Forks => is_fork(n),
+            Keep::ForksAnd(nodes) => is_fork(n) || nodes.contains(n),
+            Keep::Only(nodes) => nodes.contains(n),
+        })
+        .collect();
+    let mut graph = Graph::new();
+    let mut index = HashMap::new();
+    for &n in &is_kept {
+        index.insert(n.clone(), graph.add_node(n.clone()));
+    }
+    let successors = |n: &N| adjacency.get(n).map_or(&[][..], |ss| ss.as_slice());
+    for &start in &is_kept {
+        for first in successors(start) {
+            let mut cells = vec![start.clone()];
+            let mut prev = start;
+            let mut curr = first;
+            let mut len = 1;
+            let end = loop {
+                if with_cells {
+                    cells.push(curr.clone());
+                }
+                if is_kept.contains(curr) {
+                    break Some(curr);
+                }
+                let mut onward = successors(curr).iter().filter(|&n| n != prev);
+                let Some(next) = onward.next() else {
+                    break None;
+                };
+                assert!(
+                    onward.next().is_none(),
+                    "Corridor from {start:?} forks at {curr:?}, so it must be kept"
+                );
+                (prev, curr) = (curr, next);
+                len += 1;
+            };
+            if let Some(end) = end {
+                let corridor = Corridor {
+                    len,
+                    cells: with_cells.then_some(cells),
+                };
+                graph.add_edge(index[start], index[end], corridor);
+            }
+        }
+    }
+    Contracted { graph, index }
+}
+
+/// Contracts a grid, where each open cell connects to its orthogonally adjacent
+/// open cells.
+pub fn contract_grid<T, F>(
+    grid: &Grid<T>,
+    is_open: F,
+    keep: Keep<Pt>,
+    with_cells: bool,
+) -> Contracted<Pt>
+where
+    F: Fn(&T) -> bool,
+{
+    let adjacency = grid
+        .points()
+        .filter(|&p| is_open(&grid[p]))
+        .map(|p| {
+            let succs = grid
+                .neighbors(p)
+                .map(|(_, q)| q)
+                .filter(|&q| is_open(&grid[q]))
+                .collect();
+            (p, succs)
+        })
+        .collect();
+    contract(&adjacency, keep, with_cells)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAZE: &str = "\
+#S#####
+#.....#
+#.###.#
+#.#...#
+#.#.#.#
+#...#E#
+#######";
+
+    fn grid() -> Grid<char> {
+        MAZE.parse().unwrap()
+    }
+
+    #[test]
+    fn forks() {
+        let grid = grid();
+        let mini = contract_grid(&grid, |&c| c != '#', Keep::Forks, true);
+        // S, E, the fork below S, and the fork above E
+        assert_eq!(4, mini.graph.node_count());
+        let start = mini.index[&(1, 0)];
+        let edges: Vec<_> = mini.graph.edges(start).collect();
+        assert_eq!(1, edges.len());
+        let corridor = edges[0].weight();
+        assert_eq!(1, corridor.len);
+        assert_eq!(Some(vec![(1, 0), (1, 1)]), corridor.cells);
+        // every corridor is there both ways
+        for e in mini.graph.edge_indices() {
+            let (a, b) = mini.graph.edge_endpoints(e).unwrap();
+            let len = mini.graph[e].len;
+            assert!(mini
+                .graph
+                .edges_connecting(b, a)
+                .any(|r| r.weight().len == len));
+        }
+        let total: usize = mini.graph.edge_weights().map(|c| c.len).sum();
+        assert_eq!(2 * (1 + 6 + 10 + 2), total);
+    }
+
+    #[test]
+    fn explicit_keeps() {
+        let grid = grid();
+        let ends = HashSet::from([(1, 0), (5, 5)]);
+        let mini = contract_grid(&grid, |&c| c == '.', Keep::ForksAnd(ends.clone()), false);
+        assert_eq!(None, mini.index.get(&(1, 0)));
+        let mini = contract_grid(&grid, |&c| c != '#', Keep::ForksAnd(ends), false);
+        assert!(mini.graph.edge_weights().all(|c| c.cells.is_none()));
+
+        // a plain corridor, one way
+        let adjacency = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![4]), (4, vec![])]);
+        let mini = contract(&adjacency, Keep::Only(HashSet::from([1, 4])), true);
+        assert_eq!(1, mini.graph.edge_count());
+        let c = mini.graph.edge_weights().next().unwrap();
+        assert_eq!(3, c.len);
+        assert_eq!(Some(vec![1, 2, 3, 4]), c.cells);
+        let mini = contract(&adjacency, Keep::Forks, false);
+        assert_eq!(2, mini.graph.node_count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unkept_fork() {
+        let adjacency = HashMap::from([(1, vec![2]), (2, vec![3, 4])]);
+        contract(&adjacency, Keep::Only(HashSet::from([1, 3, 4])), false);
+    }
+}
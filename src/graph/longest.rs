@@ -0,0 +1,361 @@
+//! The longest simple path between two nodes of a small graph, by exhaustive
+//! depth-first search. Nodes are re-indexed as integers so visited sets are
+//! bitmasks, which limits graphs to 128 nodes. Branches which can't beat the
+//! best path found so far are pruned, and the search may be split across
+//! threads.
+//!
+//! ```
+//! # use aoc::graph::longest::Longest;
+//! # use std::collections::HashMap;
+//! let adjacency = HashMap::from([
+//!     ('a', vec![('b', 1), ('c', 5)]),
+//!     ('b', vec![('c', 1), ('d', 1)]),
+//!     ('c', vec![('b', 1), ('d', 1)]),
+//! ]);
+//! let longest = Longest::from_adjacency(&adjacency, &'a', &'d');
+//!
+//! assert_eq!(Some(7), longest.solve());
+//! assert_eq!(None, Longest::from_adjacency(&adjacency, &'d', &'a').solve());
+//! ```
+//!
+//! Contract corridors first (see [contract](super::contract)), so the graph is
+//! small enough.
+use petgraph::graph::{IndexType, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::{EdgeType, Graph};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// A longest-path problem, ready to [solve](Longest::solve).
+#[derive(Clone, Debug)]
+pub struct Longest {
+    /// Each node's successors, and the lengths of the edges to them.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    goal: usize,
+    threads: usize,
+}
+
+impl Longest {
+    /// The problem for a petgraph graph, with each edge's length given by the
+    /// passed function. Undirected edges may be taken either way.
+    pub fn from_graph<N, E, Ty, Ix, F>(
+        graph: &Graph<N, E, Ty, Ix>,
+        start: NodeIndex<Ix>,
+        goal: NodeIndex<Ix>,
+        length: F,
+    ) -> Longest
+    where
+        Ty: EdgeType,
+        Ix: IndexType,
+        F: Fn(&E) -> usize,
+    {
+        let adjacency = graph
+            .node_indices()
+            .map(|n| {
+                graph
+                    .edges(n)
+                    .map(|e| {
+                        let other = if e.source() == n {
+                            e.target()
+                        } else {
+                            e.source()
+                        };
+                        (other.index(), length(e.weight()))
+                    })
+                    .collect()
+            })
+            .collect();
+        Longest::new(adjacency, start.index(), goal.index())
+    }
+
+    /// The problem for an adjacency map of each node's successors, and the
+    /// lengths of the edges to them.
+    pub fn from_adjacency<N>(
+        adjacency: &HashMap<N, Vec<(N, usize)>>,
+        start: &N,
+        goal: &N,
+    ) -> Longest
+    where
+        N: Eq + Hash,
+    {
+        let mut index = HashMap::new();
+        let mut idx = |n| {
+            let len = index.len();
+            *index.entry(n).or_insert(len)
+        };
+        let (start, goal) = (idx(start), idx(goal));
+        let mut indexed = Vec::new();
+        for (n, succs) in adjacency {
+            let n = idx(n);
+            let succs: Vec<_> = succs.iter().map(|(s, len)| (idx(s), *len)).collect();
+            indexed.resize_with(indexed.len().max(n + 1), Vec::new);
+            indexed[n] = succs;
+        }
+        indexed.resize_with(index.len(), Vec::new);
+        Longest::new(indexed, start, goal)
+    }
+
+    fn new(adjacency: Vec<Vec<(usize, usize)>>, start: usize, goal: usize) -> Longest {
+        assert!(
+            adjacency.len() <= u128::BITS as usize,
+            "Only graphs of up to 128 nodes are supported, not {}",
+            adjacency.len()
+        );
+        Longest {
+            adjacency,
+            start,
+            goal,
+            threads: 1,
+        }
+    }
+
+    /// Splits the search across up to this many threads.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// The length of the longest path from the start to the goal which visits
+    /// no node twice, or `None` if there's no path at all.
+    pub fn solve(&self) -> Option<usize> {
+        if self.adjacency.len() <= u64::BITS as usize {
+            self.solve_with::<u64>()
+        } else {
+            self.solve_with::<u128>()
+        }
+    }
+
+    fn solve_with<M: Mask>(&self) -> Option<usize> {
+        let mut best_out: Vec<_> = self
+            .adjacency
+            .iter()
+            .map(|succs| succs.iter().map(|&(_, len)| len).max().unwrap_or(0))
+            .collect();
+        if self.goal < best_out.len() {
+            best_out[self.goal] = 0;
+        }
+        // if there's only one way into the goal, reaching it means going in
+        let mut into_goal = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .filter(|(_, succs)| succs.iter().any(|&(s, _)| s == self.goal))
+            .map(|(n, _)| n);
+        let last = match (into_goal.next(), into_goal.next()) {
+            (Some(n), None) => {
+                let len = self.adjacency[n]
+                    .iter()
+                    .filter(|&&(s, _)| s == self.goal)
+                    .map(|&(_, len)| len)
+                    .max()
+                    .unwrap();
+                Some((n, [(self.goal, len)]))
+            }
+            _ => None,
+        };
+        let search = Dfs {
+            adjacency: &self.adjacency,
+            best_out: &best_out,
+            goal: self.goal,
+            last,
+            best: AtomicUsize::new(0),
+        };
+        let remaining = best_out.iter().sum::<usize>() - best_out[self.start];
+        let root = State {
+            node: self.start,
+            visited: M::bit(self.start),
+            dist: 0,
+            remaining,
+        };
+        if self.threads == 1 {
+            search.dfs(root);
+        } else {
+            // subtrees vary wildly in size, so make plenty, for threads which
+            // finish small ones early to pick up more
+            let states = search.split(root, self.threads * 8);
+            let next = AtomicUsize::new(0);
+            thread::scope(|s| {
+                for _ in 0..self.threads {
+                    s.spawn(|| {
+                        while let Some(&state) = states.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            search.dfs(state);
+                        }
+                    });
+                }
+            });
+        }
+        // the best is stored plus one, so zero means none found
+        search.best.into_inner().checked_sub(1)
+    }
+}
+
+/// A visited set of node indices.
+trait Mask: Copy + Send + Sync + 'static {
+    fn bit(i: usize) -> Self;
+
+    fn has(self, i: usize) -> bool;
+
+    fn with(self, i: usize) -> Self;
+}
+
+macro_rules! impl_mask {
+    ($($type:ty),*) => {
+        $(
+            impl Mask for $type {
+                fn bit(i: usize) -> Self {
+                    1 << i
+                }
+
+                fn has(self, i: usize) -> bool {
+                    self & (1 << i) != 0
+                }
+
+                fn with(self, i: usize) -> Self {
+                    self | (1 << i)
+                }
+            }
+        )*
+    };
+}
+
+impl_mask!(u64, u128);
+
+#[derive(Clone, Copy, Debug)]
+struct State<M> {
+    node: usize,
+    visited: M,
+    dist: usize,
+    /// The sum of the best edge out of each unvisited node.
+    remaining: usize,
+}
+
+struct Dfs<'a> {
+    adjacency: &'a [Vec<(usize, usize)>],
+    best_out: &'a [usize],
+    goal: usize,
+    /// The only node leading into the goal, and the longest edge from it into
+    /// the goal, which is the only one worth taking.
+    last: Option<(usize, [(usize, usize); 1])>,
+    best: AtomicUsize,
+}
+
+impl Dfs<'_> {
+    /// The states one step on from this one, or `None` if it's at the goal or
+    /// can't beat the best path so far. Reaching the goal is recorded.
+    fn successors<M: Mask>(&self, state: State<M>) -> Option<impl Iterator<Item = State<M>> + '_> {
+        let State {
+            node,
+            visited,
+            dist,
+            remaining,
+        } = state;
+        if node == self.goal {
+            self.best.fetch_max(dist + 1, Ordering::Relaxed);
+            return None;
+        }
+        // even taking every node's best edge won't beat the best so far
+        if dist + self.best_out[node] + remaining < self.best.load(Ordering::Relaxed) {
+            return None;
+        }
+        let edges = match &self.last {
+            Some((last, into_goal)) if node == *last => into_goal,
+            _ => &self.adjacency[node][..],
+        };
+        let next = edges
+            .iter()
+            .filter(move |&&(n, _)| !visited.has(n))
+            .map(move |&(n, len)| State {
+                node: n,
+                visited: visited.with(n),
+                dist: dist + len,
+                remaining: remaining - self.best_out[n],
+            });
+        Some(next)
+    }
+
+    fn dfs<M: Mask>(&self, state: State<M>) {
+        if let Some(next) = self.successors(state) {
+            for s in next {
+                self.dfs(s)
+            }
+        }
+    }
+
+    /// Expands breadth-first until there are at least `n` states to search, or
+    /// nothing more to expand.
+    fn split<M: Mask>(&self, root: State<M>, n: usize) -> Vec<State<M>> {
+        let mut states = vec![root];
+        while !states.is_empty() && states.len() < n {
+            states = states
+                .into_iter()
+                .filter_map(|s| self.successors(s))
+                .flatten()
+                .collect();
+        }
+        states
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use petgraph::Undirected;
+
+    /// A `w` by `h` grid graph, with unit edges.
+    fn lattice(w: usize, h: usize) -> Graph<(), (), Undirected> {
+        let mut g = Graph::new_undirected();
+        let nodes: Vec<_> = (0..w * h).map(|_| g.add_node(())).collect();
+        for y in 0..h {
+            for x in 0..w {
+                if x + 1 < w {
+                    g.add_edge(nodes[y * w + x], nodes[y * w + x + 1], ());
+                }
+                if y + 1 < h {
+                    g.add_edge(nodes[y * w + x], nodes[(y + 1) * w + x], ());
+                }
+            }
+        }
+        g
+    }
+
+    #[test]
+    fn lattices() {
+        // corner to corner of a 4x4 must skip a node, for parity
+        let g = lattice(4, 4);
+        let (start, goal) = (NodeIndex::new(0), NodeIndex::new(15));
+        let longest = Longest::from_graph(&g, start, goal, |_| 1);
+        assert_eq!(Some(14), longest.solve());
+        assert_eq!(Some(14), longest.clone().threads(4).solve());
+        // adjacent corners of a 3x3 can still visit every node
+        let g = lattice(3, 3);
+        let goal = NodeIndex::new(2);
+        assert_eq!(Some(8), Longest::from_graph(&g, start, goal, |_| 1).solve());
+        // more than 64 nodes: the long way round a ring of 100
+        let mut g = Graph::new_undirected();
+        let nodes: Vec<_> = (0..100).map(|_| g.add_node(())).collect();
+        for i in 0..100 {
+            g.add_edge(nodes[i], nodes[(i + 1) % 100], ());
+        }
+        let longest = Longest::from_graph(&g, start, nodes[30], |_| 1).threads(3);
+        assert_eq!(Some(70), longest.solve());
+    }
+
+    #[test]
+    fn directed() {
+        let mut g = Graph::new();
+        let a = g.add_node('a');
+        let b = g.add_node('b');
+        let c = g.add_node('c');
+        g.add_edge(a, b, 10);
+        g.add_edge(b, c, 1);
+        g.add_edge(a, c, 20);
+        g.add_edge(c, b, 2);
+        assert_eq!(Some(22), Longest::from_graph(&g, a, b, |&w| w).solve());
+        assert_eq!(Some(20), Longest::from_graph(&g, a, c, |&w| w).solve());
+        assert_eq!(None, Longest::from_graph(&g, b, a, |&w| w).solve());
+        assert_eq!(Some(0), Longest::from_graph(&g, a, a, |&w| w).solve());
+    }
+}
@@ -0,0 +1,292 @@
+//! The structure of undirected graphs: cliques, triangles, connected components
+//! and k-cores. Everything returns node weights, ordered by node index, so
+//! results are deterministic. Self-loops and parallel edges are ignored.
+//!
+//! ```
+//! # use aoc::graph::undirected::{components, max_clique, triangles};
+//! # use petgraph::graph::UnGraph;
+//! let mut g = UnGraph::<char, ()>::default();
+//! let [a, b, c, d, _] = ['a', 'b', 'c', 'd', 'e'].map(|n| g.add_node(n));
+//! g.extend_with_edges([(a, b), (b, c), (c, a), (c, d)]);
+//!
+//! assert_eq!(vec!['a', 'b', 'c'], max_clique(&g));
+//! assert_eq!(vec![['a', 'b', 'c']], triangles(&g));
+//! assert_eq!(vec![vec!['a', 'b', 'c', 'd'], vec!['e']], components(&g));
+//! ```
+use petgraph::graph::{IndexType, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::{Graph, Undirected};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+/// Each node's distinct neighbors, by index, excluding itself.
+fn neighbor_sets<N, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>) -> Vec<HashSet<usize>> {
+    let mut adj = vec![HashSet::new(); g.node_count()];
+    for e in g.edge_references() {
+        let (u, v) = (e.source().index(), e.target().index());
+        if u != v {
+            adj[u].insert(v);
+            adj[v].insert(u);
+        }
+    }
+    adj
+}
+
+fn weights<N: Clone, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>, nodes: &[usize]) -> Vec<N> {
+    nodes
+        .iter()
+        .map(|&i| g[NodeIndex::new(i)].clone())
+        .collect()
+}
+
+/// Bron–Kerbosch with pivoting: reports every maximal clique extending `r`
+/// with nodes from `p`, and none from `x`.
+fn bron_kerbosch<F>(
+    adj: &[HashSet<usize>],
+    r: &mut Vec<usize>,
+    mut p: HashSet<usize>,
+    mut x: HashSet<usize>,
+    report: &mut F,
+) where
+    F: FnMut(&[usize], usize) -> bool,
+{
+    if p.is_empty() {
+        if x.is_empty() {
+            report(r, 0);
+        }
+        return;
+    }
+    // the caller may not care for cliques this can't grow beyond
+    if !report(r, p.len()) {
+        return;
+    }
+    // any maximal clique includes the pivot or a non-neighbor of it
+    let pivot = p
+        .union(&x)
+        .copied()
+        .max_by_key(|&u| p.intersection(&adj[u]).count())
+        .unwrap();
+    let mut candidates: Vec<_> = p.difference(&adj[pivot]).copied().collect();
+    candidates.sort();
+    for v in candidates {
+        r.push(v);
+        let p_v = p.intersection(&adj[v]).copied().collect();
+        let x_v = x.intersection(&adj[v]).copied().collect();
+        bron_kerbosch(adj, r, p_v, x_v, report);
+        r.pop();
+        p.remove(&v);
+        x.insert(v);
+    }
+}
+
+/// Every maximal clique: sets of mutually adjacent nodes, which no other node
+/// is adjacent to all of. Isolated nodes are cliques of one.
+pub fn maximal_cliques<N: Clone, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>) -> Vec<Vec<N>> {
+    let adj = neighbor_sets(g);
+    let mut cliques = Vec::new();
+    let mut report = |r: &[usize], growth: usize| {
+        if growth == 0 {
+            let mut clique = r.to_vec();
+            clique.sort();
+            cliques.push(clique);
+        }
+        true
+    };
+    let all = (0..adj.len()).collect();
+    bron_kerbosch(&adj, &mut Vec::new(), all, HashSet::new(), &mut report);
+    cliques.sort();
+    cliques.iter().map(|c| weights(g, c)).collect()
+}
+
+/// A largest clique, or the one with the lowest node indices if there are
+/// several. Empty only for an empty graph.
+pub fn max_clique<N: Clone, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>) -> Vec<N> {
+    let adj = neighbor_sets(g);
+    let mut best: Vec<usize> = Vec::new();
+    let mut report = |r: &[usize], growth: usize| {
+        if growth > 0 {
+            // prune branches which can't even match the best so far
+            return r.len() + growth >= best.len();
+        }
+        let mut clique = r.to_vec();
+        clique.sort();
+        if clique.len() > best.len() || (clique.len() == best.len() && clique < best) {
+            best = clique;
+        }
+        true
+    };
+    let all = (0..adj.len()).collect();
+    bron_kerbosch(&adj, &mut Vec::new(), all, HashSet::new(), &mut report);
+    weights(g, &best)
+}
+
+/// Every triangle: three mutually adjacent nodes, each listed once.
+pub fn triangles<N: Clone, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>) -> Vec<[N; 3]> {
+    let adj = neighbor_sets(g);
+    let mut found = Vec::new();
+    for (u, adj_u) in adj.iter().enumerate() {
+        for &v in adj_u.iter().filter(|&&v| v > u) {
+            for &w in adj_u.intersection(&adj[v]).filter(|&&w| w > v) {
+                found.push([u, v, w]);
+            }
+        }
+    }
+    found.sort();
+    found
+        .into_iter()
+        .map(|t| t.map(|i| g[NodeIndex::new(i)].clone()))
+        .collect()
+}
+
+/// The connected components, ordered by their lowest node index.
+pub fn components<N: Clone, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>) -> Vec<Vec<N>> {
+    let adj = neighbor_sets(g);
+    let mut seen = vec![false; adj.len()];
+    let mut found = Vec::new();
+    for root in 0..adj.len() {
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+        let mut component = vec![root];
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            for &v in &adj[u] {
+                if !seen[v] {
+                    seen[v] = true;
+                    component.push(v);
+                    stack.push(v);
+                }
+            }
+        }
+        component.sort();
+        found.push(weights(g, &component));
+    }
+    found
+}
+
+/// Each node's core number: the largest `k` for which it's in the k-core.
+fn core_indices(adj: &[HashSet<usize>]) -> Vec<usize> {
+    let mut degree: Vec<_> = adj.iter().map(|ns| ns.len()).collect();
+    let mut core = vec![0; adj.len()];
+    let mut removed = vec![false; adj.len()];
+    let mut queue: BinaryHeap<_> = degree
+        .iter()
+        .enumerate()
+        .map(|(u, &d)| Reverse((d, u)))
+        .collect();
+    let mut k = 0;
+    // peel off the least connected node, until none are left
+    while let Some(Reverse((d, u))) = queue.pop() {
+        if removed[u] || d != degree[u] {
+            continue;
+        }
+        removed[u] = true;
+        k = k.max(d);
+        core[u] = k;
+        for &v in &adj[u] {
+            if !removed[v] {
+                degree[v] -= 1;
+                queue.push(Reverse((degree[v], v)));
+            }
+        }
+    }
+    core
+}
+
+/// Each node with its core number: the largest `k` for which it's in the
+/// [k_core].
+pub fn core_numbers<N: Clone, E, Ix: IndexType>(
+    g: &Graph<N, E, Undirected, Ix>,
+) -> Vec<(N, usize)> {
+    let core = core_indices(&neighbor_sets(g));
+    g.node_weights().cloned().zip(core).collect()
+}
+
+/// The k-core: the largest subgraph in which every node has at least `k`
+/// neighbors.
+pub fn k_core<N: Clone, E, Ix: IndexType>(g: &Graph<N, E, Undirected, Ix>, k: usize) -> Vec<N> {
+    let core = core_indices(&neighbor_sets(g));
+    let nodes: Vec<_> = (0..core.len()).filter(|&u| core[u] >= k).collect();
+    weights(g, &nodes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use petgraph::graph::UnGraph;
+
+    /// Two squares joined at a corner, one with both diagonals, plus an
+    /// isolated node and a self-loop.
+    fn graph() -> UnGraph<u8, ()> {
+        let mut g = UnGraph::new_undirected();
+        for n in 0..8 {
+            g.add_node(n);
+        }
+        g.extend_with_edges([
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (0, 2),
+            (1, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 3),
+            (1, 0),
+            (7, 7),
+        ]);
+        g
+    }
+
+    #[test]
+    fn cliques() {
+        let g = graph();
+        assert_eq!(vec![0, 1, 2, 3], max_clique(&g));
+        assert_eq!(
+            vec![
+                vec![0, 1, 2, 3],
+                vec![3, 4],
+                vec![3, 6],
+                vec![4, 5],
+                vec![5, 6],
+                vec![7]
+            ],
+            maximal_cliques(&g)
+        );
+        assert!(max_clique(&UnGraph::<u8, ()>::default()).is_empty());
+        // ties go to the lowest indices
+        let mut g = UnGraph::<u8, ()>::default();
+        let [a, b, c, d] = [0, 1, 2, 3].map(|n| g.add_node(n));
+        g.extend_with_edges([(c, d), (a, b)]);
+        assert_eq!(vec![0, 1], max_clique(&g));
+    }
+
+    #[test]
+    fn triangles_and_components() {
+        let g = graph();
+        let expected = vec![[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]];
+        assert_eq!(expected, triangles(&g));
+        assert_eq!(vec![vec![0, 1, 2, 3, 4, 5, 6], vec![7]], components(&g));
+    }
+
+    #[test]
+    fn cores() {
+        let g = graph();
+        let expected = vec![
+            (0, 3),
+            (1, 3),
+            (2, 3),
+            (3, 3),
+            (4, 2),
+            (5, 2),
+            (6, 2),
+            (7, 0),
+        ];
+        assert_eq!(expected, core_numbers(&g));
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], k_core(&g, 2));
+        assert_eq!(vec![0, 1, 2, 3], k_core(&g, 3));
+        assert!(k_core(&g, 4).is_empty());
+    }
+}
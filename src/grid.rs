@@ -0,0 +1,401 @@
+//! A rectangular [Grid] of cells, as so many puzzles' inputs are, addressed by
+//! `(x, y)` points with the origin at the top left, and `y` increasing down
+//! (i.e., [South]).
+//!
+//! ```
+//! # use aoc::grid::Grid;
+//! # use aoc::geom2d::Dir;
+//! let grid: Grid<char> = "S.#\n..#\n#.E".parse().unwrap();
+//!
+//! let start = grid.find(|&c| c == 'S').unwrap();
+//! assert_eq!((0, 0), start);
+//! assert_eq!(Some(&'#'), grid.get((2, 1)));
+//! assert_eq!(None, grid.get((3, 1)));
+//! let open: Vec<_> = grid
+//!     .neighbors(start)
+//!     .filter(|&(_, p)| grid[p] != '#')
+//!     .map(|(d, _)| d)
+//!     .collect();
+//! assert_eq!(vec![Dir::East, Dir::South], open);
+//! ```
+use crate::geom2d::Dir;
+use crate::geom2d::Dir::*;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A point in a [Grid].
+pub type Pt = (usize, usize);
+
+/// A rectangular grid of cells, stored row-major.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Why a [Grid] couldn't be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GridError {
+    /// A line's length differs from the first line's.
+    Ragged {
+        line: usize,
+        len: usize,
+        width: usize,
+    },
+    /// A character couldn't be converted to a cell, at the given point.
+    BadChar { c: char, at: Pt },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Ragged { line, len, width } => {
+                write!(f, "Line {line} has {len} cells, not {width}")
+            }
+            GridError::BadChar { c, at } => write!(f, "Unexpected '{c}' at {at:?}"),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl<T> Grid<T> {
+    /// A grid of the passed row-major cells. Panics unless there are exactly
+    /// `width * height` of them.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "A {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid of one cell per character, converting each with `f`.
+    /// Lines must all be the same length, and trailing newlines are ignored.
+    pub fn parse_with<F>(input: &str, mut f: F) -> Result<Grid<T>, GridError>
+    where
+        F: FnMut(char) -> T,
+    {
+        Grid::try_parse_with(input, |c, _| Ok(f(c)))
+    }
+
+    fn try_parse_with<F>(input: &str, mut f: F) -> Result<Grid<T>, GridError>
+    where
+        F: FnMut(char, Pt) -> Result<T, GridError>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for (y, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let mut len = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c, (x, y))?);
+                len += 1;
+            }
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(GridError::Ragged {
+                        line: y + 1,
+                        len,
+                        width,
+                    })
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Pt) -> bool {
+        p.0 < self.width && p.1 < self.height
+    }
+
+    /// The index into the row-major cells of the passed point, which must be
+    /// in the grid.
+    pub fn to_index(&self, p: Pt) -> usize {
+        p.1 * self.width + p.0
+    }
+
+    /// The point at the passed index into the row-major cells.
+    pub fn to_point(&self, i: usize) -> Pt {
+        (i % self.width, i / self.width)
+    }
+
+    pub fn get(&self, p: Pt) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[self.to_index(p)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Pt) -> Option<&mut T> {
+        if self.contains(p) {
+            let i = self.to_index(p);
+            Some(&mut self.cells[i])
+        } else {
+            None
+        }
+    }
+
+    /// The point one step in the passed direction, if it's in the grid.
+    pub fn step(&self, p: Pt, d: Dir) -> Option<Pt> {
+        let (x, y) = p;
+        let q = match d {
+            North => (x, y.checked_sub(1)?),
+            East => (x + 1, y),
+            South => (x, y + 1),
+            West => (x.checked_sub(1)?, y),
+        };
+        if self.contains(q) {
+            Some(q)
+        } else {
+            None
+        }
+    }
+
+    /// The in-grid points orthogonally adjacent to the passed one, each with
+    /// its direction, clockwise from [North].
+    pub fn neighbors(&self, p: Pt) -> impl Iterator<Item = (Dir, Pt)> + '_ {
+        [North, East, South, West]
+            .into_iter()
+            .filter_map(move |d| self.step(p, d).map(|q| (d, q)))
+    }
+
+    /// The in-grid points orthogonally or diagonally adjacent to the passed
+    /// one, clockwise from [North]. Each diagonal is a step in the preceding
+    /// direction, then one to its right.
+    pub fn neighbors8(&self, p: Pt) -> impl Iterator<Item = Pt> + '_ {
+        [North, East, South, West].into_iter().flat_map(move |d| {
+            let ortho = self.step(p, d);
+            let diag = ortho.and_then(|q| self.step(q, d.turn_right()));
+            [ortho, diag].into_iter().flatten()
+        })
+    }
+
+    /// Every point in the grid, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Pt> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell in the grid with its point, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pt, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, in row-major order, whose cell matches.
+    pub fn find<P>(&self, predicate: P) -> Option<Pt>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.to_point(i))
+    }
+
+    /// Every point, in row-major order, whose cell matches.
+    pub fn find_all<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Pt> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, c)| predicate(c))
+            .map(|(p, _)| p)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, but there are no rows to yield anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same size, with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Pt> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Pt) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pt> for Grid<T> {
+    fn index_mut(&mut self, p: Pt) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"))
+    }
+}
+
+/// Parses a cell per character, via `TryFrom<char>`.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+{
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::try_parse_with(s, |c, at| {
+            T::try_from(c).map_err(|_| GridError::BadChar { c, at })
+        })
+    }
+}
+
+/// Each row on its own line, without separators between cells.
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for c in row {
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#..S
+.#..
+E..#
+";
+
+    #[test]
+    fn parsing() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(4, grid.width());
+        assert_eq!(3, grid.height());
+        assert_eq!(EXAMPLE.trim_end(), grid.to_string());
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(Ok(Grid::new(2, 2, vec![1, 2, 3, 4])), digits);
+        let bytes: Grid<u8> = "ab\ncd".parse().unwrap();
+        assert_eq!(b'd', bytes[(1, 1)]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(GridError::Ragged {
+                line: 2,
+                len: 3,
+                width: 2
+            }),
+            "ab\ncde".parse::<Grid<char>>()
+        );
+        assert_eq!(
+            Err(GridError::BadChar {
+                c: '€', at: (1, 1)
+            }),
+            "ab\nc€".parse::<Grid<u8>>()
+        );
+    }
+
+    #[test]
+    fn lookups() {
+        let mut grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(Some((3, 0)), grid.find(|&c| c == 'S'));
+        assert_eq!(None, grid.find(|&c| c == 'X'));
+        let walls: Vec<_> = grid.find_all(|&c| c == '#').collect();
+        assert_eq!(vec![(0, 0), (1, 1), (3, 2)], walls);
+        assert_eq!(Some(&'E'), grid.get((0, 2)));
+        assert_eq!(None, grid.get((0, 3)));
+        assert_eq!(None, grid.get((4, 0)));
+        grid[(0, 2)] = '.';
+        assert_eq!('.', grid[(0, 2)]);
+        assert_eq!(7, grid.to_index((3, 1)));
+        assert_eq!((3, 1), grid.to_point(7));
+    }
+
+    #[test]
+    fn neighborhoods() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        let ns: Vec<_> = grid.neighbors((0, 0)).collect();
+        assert_eq!(vec![(East, (1, 0)), (South, (0, 1))], ns);
+        assert_eq!(4, grid.neighbors((1, 1)).count());
+        let ns: Vec<_> = grid.neighbors8((1, 1)).collect();
+        assert_eq!(
+            vec![
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1),
+                (0, 0)
+            ],
+            ns
+        );
+        assert_eq!(3, grid.neighbors8((3, 2)).count());
+    }
+
+    #[test]
+    fn views() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(&['.', '#', '.', '.'], grid.row(1));
+        assert_eq!(3, grid.rows().count());
+        assert_eq!("S.#", grid.column(3).collect::<String>());
+        let cols: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(vec!["#.E", ".#.", "...", "S.#"], cols);
+        let walls = grid.map(|&c| c == '#');
+        assert!(walls[(1, 1)]);
+        assert_eq!(0, "".parse::<Grid<char>>().unwrap().rows().count());
+    }
+}
@@ -1,36 +1,140 @@
 //! A [Histogram] type, and an [IntoHistogram] trait for constructing one from
-//! anything `IntoIterator`. Includes ASCII-art `Debug` formatting.
+//! anything `IntoIterator`. Histograms can be added together and scaled, which
+//! suits solvers tracking counts of identical states across generations. Counts
+//! are `usize` by default, but may be any [Count] type, and a histogram may be
+//! [checked](Histogram::checked) for overflow. Includes ASCII-art `Debug`
+//! formatting, and other [render]ings.
 use std::borrow::Borrow;
 use std::collections::hash_map::{IntoIter, Keys, Values};
 use std::collections::HashMap;
+use std::error::Error;
 use std::fmt;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
-use std::ops::{Deref, DerefMut};
+use std::iter::Sum;
+// not importing Add, which would hide the inherent `add` from owned histograms
+use std::ops;
+use std::ops::{AddAssign, Deref, DerefMut, Mul, MulAssign, Sub, SubAssign};
+
+pub mod render;
+
+/// An integer type which a [Histogram] can count with.
+pub trait Count:
+    Copy
+    + Debug
+    + Default
+    + Display
+    + Hash
+    + Ord
+    + Sum
+    + ops::Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+{
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Approximately this count, for drawing and statistics.
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_count {
+    ($($type:ty),*) => {
+        $(
+            impl Count for $type {
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$type>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$type>::checked_mul(self, rhs)
+                }
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_count!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A count in a [checked](Histogram::checked) histogram overflowed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OverflowError;
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Histogram count overflowed")
+    }
+}
+
+impl Error for OverflowError {}
+
+/// A histogram, backed by a [HashMap], counting with `usize` unless otherwise
+/// specified. Unless [checked](Histogram::checked), overflowing counts follow
+/// the usual integer rules, panicking in debug builds and wrapping in release.
+///
+/// ```
+/// # use aoc::hist::Histogram;
+/// let mut hist: Histogram<&str, u8> = Histogram::default().checked();
+/// hist.add("rabbit", 200);
+///
+/// assert!(hist.try_add("rabbit", 100).is_err());
+/// assert_eq!(200, hist.count("rabbit"));
+/// ```
+#[derive(Clone)]
+pub struct Histogram<T, C = usize>
+where
+    T: Eq + Hash,
+{
+    map: HashMap<T, C>,
+    checked: bool,
+}
 
-/// A `usize`-valued histogram, backed by a [HashMap].
-#[derive(Clone, Default)]
-pub struct Histogram<T>
+impl<T, C> Default for Histogram<T, C>
 where
     T: Eq + Hash,
 {
-    map: HashMap<T, usize>,
+    fn default() -> Self {
+        Histogram {
+            map: HashMap::new(),
+            checked: false,
+        }
+    }
 }
 
-impl<T> Eq for Histogram<T> where T: Eq + Hash {}
+impl<T, C> Eq for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+}
 
-impl<T> PartialEq for Histogram<T>
+/// Whether histograms are checked doesn't affect equality.
+impl<T, C> PartialEq for Histogram<T, C>
 where
     T: Eq + Hash,
+    C: Count,
 {
     fn eq(&self, other: &Self) -> bool {
         self.map == other.map
     }
 }
 
-impl<T> Hash for Histogram<T>
+impl<T, C> Hash for Histogram<T, C>
 where
     T: Eq + Hash,
+    C: Count,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
         for (b, n) in &self.map {
@@ -44,16 +148,33 @@ impl<T> Histogram<T>
 where
     T: Eq + Hash,
 {
+    /// Creates a `usize`-valued histogram. Use `default` for other counts.
     pub fn new() -> Histogram<T> {
-        Histogram {
-            map: HashMap::new(),
-        }
+        Histogram::default()
+    }
+}
+
+impl<T, C> Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    /// Switches this histogram to checked mode, where any overflowing count
+    /// panics, even in release builds. The `try_` methods report overflow
+    /// whether checked or not.
+    pub fn checked(mut self) -> Self {
+        self.checked = true;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
     }
 
     /// Get the count for a given element from the histogram. Returns zero for
     /// unknown elements. If you need to test existence, use `Deref`-coercion to
     /// `HashMap` for `get` or `contains_key`.
-    pub fn count<Q: ?Sized>(&self, k: &Q) -> usize
+    pub fn count<Q: ?Sized>(&self, k: &Q) -> C
     where
         T: Borrow<Q>,
         Q: Hash + Eq,
@@ -62,40 +183,233 @@ where
     }
 
     /// Returns an `Iterator` over the buckets in the histogram.
-    pub fn buckets(&self) -> Keys<'_, T, usize> {
+    pub fn buckets(&self) -> Keys<'_, T, C> {
         self.map.keys()
     }
 
     /// Returns an `Iterator` over each bucket's count in the histogram.
-    pub fn counts(&self) -> Values<'_, T, usize> {
+    pub fn counts(&self) -> Values<'_, T, C> {
         self.map.values()
     }
 
     pub fn increment(&mut self, t: T) {
-        self.add(t, 1)
+        self.add(t, C::ONE)
+    }
+
+    pub fn add(&mut self, t: T, n: C) {
+        if self.checked {
+            self.try_add(t, n).unwrap()
+        } else {
+            *self.map.entry(t).or_default() += n
+        }
+    }
+
+    /// Adds to an element's count, unless it would overflow.
+    pub fn try_add(&mut self, t: T, n: C) -> Result<(), OverflowError> {
+        let c = self.map.entry(t).or_default();
+        *c = c.checked_add(n).ok_or(OverflowError)?;
+        Ok(())
+    }
+
+    /// Removes up to `n` from an element's count, saturating at zero. A bucket
+    /// reaching zero is removed entirely.
+    pub fn subtract<Q>(&mut self, k: &Q, n: C)
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(c) = self.map.get_mut(k) {
+            if *c > n {
+                *c -= n;
+            } else {
+                self.map.remove(k);
+            }
+        }
+    }
+
+    pub fn total(&self) -> C {
+        if self.checked {
+            self.try_total().unwrap()
+        } else {
+            self.map.values().copied().sum()
+        }
+    }
+
+    /// The sum of every count, unless it would overflow.
+    pub fn try_total(&self) -> Result<C, OverflowError> {
+        self.map
+            .values()
+            .try_fold(C::default(), |sum, &c| sum.checked_add(c))
+            .ok_or(OverflowError)
+    }
+
+    /// Adds every bucket of another histogram into this one, which becomes
+    /// checked if the other was.
+    pub fn merge(&mut self, other: Histogram<T, C>) {
+        self.checked |= other.checked;
+        for (t, n) in other {
+            self.add(t, n)
+        }
+    }
+
+    /// Adds every bucket of another histogram into this one, which becomes
+    /// checked if the other was, unless any count would overflow, in which
+    /// case this is unchanged.
+    pub fn try_merge(&mut self, other: Histogram<T, C>) -> Result<(), OverflowError> {
+        if other
+            .map
+            .iter()
+            .any(|(t, &n)| self.count(t).checked_add(n).is_none())
+        {
+            return Err(OverflowError);
+        }
+        self.checked |= other.checked;
+        for (t, n) in other {
+            *self.map.entry(t).or_default() += n
+        }
+        Ok(())
+    }
+
+    /// Multiplies every bucket's count by `n`. Scaling by zero empties the
+    /// histogram, as with [subtract](Histogram::subtract).
+    pub fn scale(&mut self, n: C) {
+        if self.checked {
+            self.try_scale(n).unwrap()
+        } else if n == C::default() {
+            self.map.clear()
+        } else {
+            for c in self.map.values_mut() {
+                *c *= n
+            }
+        }
+    }
+
+    /// Multiplies every bucket's count by `n`, unless any would overflow, in
+    /// which case none are changed.
+    pub fn try_scale(&mut self, n: C) -> Result<(), OverflowError> {
+        if self.map.values().any(|c| c.checked_mul(n).is_none()) {
+            return Err(OverflowError);
+        }
+        if n == C::default() {
+            self.map.clear()
+        }
+        for c in self.map.values_mut() {
+            *c *= n
+        }
+        Ok(())
+    }
+
+    /// Re-buckets every element, adding together the counts of any which map
+    /// to the same new element.
+    ///
+    /// ```
+    /// # use aoc::hist::IntoHistogram;
+    /// let parity = vec![1, 2, 3, 3, 4].into_histogram().map_keys(|n| n % 2);
+    ///
+    /// assert_eq!(3, parity.count(&1));
+    /// assert_eq!(2, parity.count(&0));
+    /// ```
+    pub fn map_keys<U, F>(self, mut f: F) -> Histogram<U, C>
+    where
+        U: Eq + Hash,
+        F: FnMut(T) -> U,
+    {
+        let mut hist = Histogram {
+            map: HashMap::new(),
+            checked: self.checked,
+        };
+        for (t, n) in self {
+            hist.add(f(t), n)
+        }
+        hist
+    }
+}
+
+/// Ordering queries, which break ties between same-sized buckets by key, so
+/// they're deterministic.
+impl<T, C> Histogram<T, C>
+where
+    T: Eq + Hash + Ord,
+    C: Count,
+{
+    /// The `n` largest buckets, largest first.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, C)> {
+        let mut buckets: Vec<_> = self.map.iter().map(|(t, &c)| (t, c)).collect();
+        buckets.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.cmp(b)));
+        buckets.truncate(n);
+        buckets
+    }
+
+    /// The `n` smallest buckets, smallest first.
+    pub fn least_common(&self, n: usize) -> Vec<(&T, C)> {
+        let mut buckets: Vec<_> = self.map.iter().map(|(t, &c)| (t, c)).collect();
+        buckets.sort_by(|(a, x), (b, y)| x.cmp(y).then(a.cmp(b)));
+        buckets.truncate(n);
+        buckets
+    }
+
+    /// The element with the largest count, or `None` if empty.
+    pub fn mode(&self) -> Option<&T> {
+        self.most_common(1).first().map(|&(t, _)| t)
+    }
+
+    /// Every bucket in key order, with the running total of counts up to and
+    /// including it.
+    pub fn cumulative(&self) -> Vec<(&T, C)> {
+        let mut buckets: Vec<_> = self.map.iter().collect();
+        buckets.sort();
+        let mut sum = C::default();
+        buckets
+            .into_iter()
+            .map(|(t, &c)| {
+                sum += c;
+                (t, sum)
+            })
+            .collect()
     }
 
-    pub fn add(&mut self, t: T, n: usize) {
-        *self.map.entry(t).or_default() += n
+    /// The smallest element for which at least `p` percent of the total count
+    /// is at or below it (i.e., the nearest-rank percentile), or `None` if
+    /// empty. Panics unless `p` is between zero and 100.
+    ///
+    /// ```
+    /// # use aoc::hist::IntoHistogram;
+    /// let hist = vec![1, 2, 2, 3, 3, 3, 3, 3, 4, 5].into_histogram();
+    ///
+    /// assert_eq!(Some(&1), hist.percentile(0.0));
+    /// assert_eq!(Some(&2), hist.percentile(30.0));
+    /// assert_eq!(Some(&3), hist.median());
+    /// assert_eq!(Some(&5), hist.percentile(100.0));
+    /// ```
+    pub fn percentile(&self, p: f64) -> Option<&T> {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "Percentile {p} isn't in 0..=100"
+        );
+        let rank = (p / 100.0 * self.total().as_f64()).ceil().max(1.0);
+        self.cumulative()
+            .into_iter()
+            .find(|&(_, sum)| sum.as_f64() >= rank)
+            .map(|(t, _)| t)
     }
 
-    pub fn total(&self) -> usize {
-        self.map.values().sum()
+    pub fn median(&self) -> Option<&T> {
+        self.percentile(50.0)
     }
 }
 
-impl<T> Deref for Histogram<T>
+impl<T, C> Deref for Histogram<T, C>
 where
     T: Eq + Hash,
 {
-    type Target = HashMap<T, usize>;
+    type Target = HashMap<T, C>;
 
     fn deref(&self) -> &Self::Target {
         &self.map
     }
 }
 
-impl<T> DerefMut for Histogram<T>
+impl<T, C> DerefMut for Histogram<T, C>
 where
     T: Eq + Hash,
 {
@@ -105,11 +419,9 @@ where
 }
 
 /// Renders an ASCII-art bar graph of the histogram, ordering buckets largest
-/// to smallest. Same-sized buckets will be ordered by their key.
-///
-/// Note that some dubious rounding choices are made around bar widths, as the
-/// idea is to give a quick summary of values, not render a verifiable chart. If
-/// you need the latter, do your own drawing.
+/// to smallest. Same-sized buckets will be ordered by their key. The alternate
+/// flag (`{:#?}`) formats the underlying map instead. For other renderings,
+/// see [Histogram::render].
 /// ```
 /// # use aoc::hist::IntoHistogram;
 /// println!("{:?}", vec![1, 2, 2, 3].into_histogram());
@@ -119,51 +431,16 @@ where
 /// //   +----------
 /// //   | 0      2
 /// ```
-impl<T> Debug for Histogram<T>
+impl<T, C> Debug for Histogram<T, C>
 where
     T: Debug + Eq + Hash + Ord,
+    C: Count,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.map.is_empty() || f.alternate() {
             return write!(f, "{:?}", self.map);
         }
-        let max = *self.map.values().max().unwrap();
-        let width: usize = if max >= 70 {
-            70
-        } else if max < 8 {
-            max * 4
-        } else if max < 12 {
-            max * 3
-        } else if max < 20 {
-            max * 2
-        } else {
-            max
-        };
-        let factor = width as f32 / max as f32;
-        let mut buckets: Vec<_> = self.buckets().collect();
-        buckets.sort();
-        let mut buckets: Vec<_> = buckets
-            .iter()
-            .rev()
-            .map(|b| {
-                let val = self.count(b);
-                (format!("{b:?}"), (val as f32 * factor).ceil() as usize)
-            })
-            .collect();
-        buckets.sort_by_key(|(_, v)| *v);
-        let len = buckets.iter().map(|(lbl, _)| lbl.len()).max().unwrap();
-        let bar = format!("{:>len$} +-{}-", "", "-".repeat(width));
-        let scale = format!("{:>len$} | 0{max:>1$}", "", width - 1);
-        if self.map.len() > 8 {
-            writeln!(f, "{scale}")?;
-            writeln!(f, "{bar}")?;
-        }
-        for (lbl, val) in buckets.iter().rev() {
-            writeln!(f, "{lbl:>len$} | {}", "#".repeat(*val))?;
-        }
-        writeln!(f, "{bar}")?;
-        writeln!(f, "{scale}")?;
-        Ok(())
+        write!(f, "{}", self.render())
     }
 }
 
@@ -198,12 +475,13 @@ where
     }
 }
 
-impl<T> FromIterator<T> for Histogram<T>
+impl<T, C> FromIterator<T> for Histogram<T, C>
 where
     T: Eq + Hash,
+    C: Count,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut hist = Histogram::new();
+        let mut hist = Histogram::default();
         for t in iter {
             hist.increment(t)
         }
@@ -211,18 +489,100 @@ where
     }
 }
 
-impl<T> IntoIterator for Histogram<T>
+impl<T, C> IntoIterator for Histogram<T, C>
 where
     T: Eq + Hash,
 {
-    type Item = (T, usize);
-    type IntoIter = IntoIter<T, usize>;
+    type Item = (T, C);
+    type IntoIter = IntoIter<T, C>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.map.into_iter()
     }
 }
 
+impl<T, C> ops::Add for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    type Output = Histogram<T, C>;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.merge(rhs);
+        self
+    }
+}
+
+impl<T, C> AddAssign for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.merge(rhs)
+    }
+}
+
+/// Subtracts bucket by bucket, as [Histogram::subtract] does.
+impl<T, C> Sub for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    type Output = Histogram<T, C>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T, C> SubAssign for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for (t, n) in rhs {
+            self.subtract(&t, n)
+        }
+    }
+}
+
+impl<T, C> Mul<C> for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    type Output = Histogram<T, C>;
+
+    fn mul(mut self, rhs: C) -> Self::Output {
+        self.scale(rhs);
+        self
+    }
+}
+
+impl<T, C> MulAssign<C> for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn mul_assign(&mut self, rhs: C) {
+        self.scale(rhs)
+    }
+}
+
+impl<T, C> Sum for Histogram<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Histogram::default(), ops::Add::add)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -280,4 +640,94 @@ mod test {
 
         assert_eq!(42, hist.count("rabbit"));
     }
+
+    #[test]
+    fn arithmetic() {
+        let a = vec![1, 1, 2].into_histogram();
+        let b = vec![2, 3].into_histogram();
+        let sum = a.clone() + b.clone();
+        assert_eq!(vec![1, 1, 2, 2, 3].into_histogram(), sum);
+        assert_eq!(sum, vec![a.clone(), b.clone()].into_iter().sum());
+        let mut tripled = sum.clone() * 3;
+        assert_eq!(6, tripled.count(&1));
+        assert_eq!(15, tripled.total());
+        tripled -= sum * 2;
+        assert_eq!(vec![1, 1, 2, 2, 3].into_histogram(), tripled);
+        assert_eq!(vec![1, 1].into_histogram(), a - b * 5);
+    }
+
+    #[test]
+    fn test_subtract() {
+        let mut hist = vec!["cow", "cow", "rabbit"].into_histogram();
+        hist.subtract("cow", 1);
+        assert_eq!(1, hist.count("cow"));
+        hist.subtract("rabbit", 2);
+        assert!(!hist.contains_key("rabbit"));
+        hist.subtract("pig", 1);
+        assert_eq!(1, hist.total());
+        hist.scale(0);
+        assert!(hist.is_empty());
+        let mut hist = vec!['a'].into_histogram().checked();
+        assert_eq!(Ok(()), hist.try_scale(0));
+        assert!(hist.is_empty());
+    }
+
+    #[test]
+    fn ordering() {
+        let hist = vec![5, 1, 2, 2, 3, 3, 4, 4, 4].into_histogram();
+        assert_eq!(vec![(&4, 3), (&2, 2), (&3, 2)], hist.most_common(3));
+        assert_eq!(vec![(&1, 1), (&5, 1)], hist.least_common(2));
+        assert_eq!(5, hist.most_common(10).len());
+        assert_eq!(Some(&4), hist.mode());
+        assert_eq!(None, Histogram::<u8>::new().mode());
+        assert_eq!(
+            vec![(&1, 1), (&2, 3), (&3, 5), (&4, 8), (&5, 9)],
+            hist.cumulative()
+        );
+        assert_eq!(Some(&3), hist.median());
+        assert_eq!(Some(&4), hist.percentile(80.0));
+        assert_eq!(Some(&5), hist.percentile(90.0));
+        assert_eq!(None, Histogram::<u8>::new().median());
+    }
+
+    #[test]
+    fn wide_counts() {
+        let mut hist: Histogram<_, u128> = vec!['a', 'b', 'b'].into_iter().collect();
+        for _ in 0..100 {
+            hist *= 2;
+        }
+        assert_eq!(1 << 100, hist.count(&'a'));
+        assert_eq!(3 << 100, hist.total());
+        assert_eq!(Some(&'b'), hist.mode());
+        let parity = vec![1, 2, 3].into_iter().collect::<Histogram<i32, u8>>();
+        assert_eq!(vec![(&1, 2)], parity.map_keys(|n| n % 2).most_common(1));
+    }
+
+    #[test]
+    fn checked() {
+        let mut hist: Histogram<_, u8> = Histogram::default().checked();
+        hist.add('a', 100);
+        hist.add('b', 156);
+        assert_eq!(Err(OverflowError), hist.try_total());
+        assert_eq!(Err(OverflowError), hist.try_scale(3));
+        assert_eq!(100, hist.count(&'a'));
+        assert_eq!(Ok(()), hist.try_add('a', 155));
+        assert_eq!(Err(OverflowError), hist.try_add('a', 1));
+        assert_eq!(255, hist.count(&'a'));
+        let mut merged = Histogram::default();
+        assert_eq!(Ok(()), merged.try_merge(hist.clone()));
+        assert!(merged.is_checked());
+        assert_eq!(Err(OverflowError), merged.try_merge(hist.clone()));
+        assert_eq!(hist, merged);
+        assert!((Histogram::default() + hist).is_checked());
+        assert!(!Histogram::<char, u8>::default().is_checked());
+    }
+
+    #[test]
+    #[should_panic]
+    fn checked_overflow() {
+        let mut hist: Histogram<_, u8> = Histogram::default().checked();
+        hist.add('a', 200);
+        hist.scale(2);
+    }
 }
@@ -0,0 +1,314 @@
+//! Configurable renderings of a [Histogram], built with [Histogram::render]:
+//! bar graphs ordered by key or by size, on a fixed or log scale, single-line
+//! sparklines, and CSV or TSV. Buckets are labeled with their `Debug` format.
+//!
+//! ```
+//! # use aoc::hist::IntoHistogram;
+//! let hist = vec![1, 2, 2, 3, 3, 3, 3].into_histogram();
+//!
+//! assert_eq!("▂▄█", hist.render().by_key().sparkline().to_string());
+//! assert_eq!(
+//!     "bucket,count\n3,4\n2,2\n1,1\n",
+//!     hist.render().csv().to_string()
+//! );
+//! ```
+use super::{Count, Histogram};
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The order buckets are rendered in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Order {
+    /// Smallest key first.
+    Key,
+    /// Largest bucket first, with same-sized buckets ordered by key.
+    Size,
+}
+
+/// How counts are scaled to bar lengths.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Scale {
+    Linear,
+    /// Logarithmic, so small buckets remain visible beside huge ones.
+    Log,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Style {
+    Bars,
+    Sparkline,
+    Delimited(char),
+}
+
+/// A rendering of a histogram, which is drawn when formatted with `Display`.
+/// By default, an ASCII-art bar graph, ordered by size, on a linear scale,
+/// sized to suit the largest bucket.
+pub struct Render<'a, T, C>
+where
+    T: Eq + Hash,
+{
+    hist: &'a Histogram<T, C>,
+    order: Order,
+    scale: Scale,
+    style: Style,
+    width: Option<usize>,
+}
+
+impl<T, C> Histogram<T, C>
+where
+    T: Debug + Eq + Hash + Ord,
+    C: Count,
+{
+    /// Starts building a rendering of this histogram.
+    pub fn render(&self) -> Render<'_, T, C> {
+        Render {
+            hist: self,
+            order: Order::Size,
+            scale: Scale::Linear,
+            style: Style::Bars,
+            width: None,
+        }
+    }
+}
+
+impl<T, C> Render<'_, T, C>
+where
+    T: Debug + Eq + Hash + Ord,
+    C: Count,
+{
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn by_key(self) -> Self {
+        self.order(Order::Key)
+    }
+
+    pub fn by_size(self) -> Self {
+        self.order(Order::Size)
+    }
+
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn log_scale(self) -> Self {
+        self.scale(Scale::Log)
+    }
+
+    /// Sets the length of the largest bar, instead of sizing it to suit.
+    pub fn width(mut self, width: usize) -> Self {
+        assert!(width > 0, "Bars must have a positive width");
+        self.width = Some(width);
+        self
+    }
+
+    /// Renders a single line, with a block character per bucket.
+    pub fn sparkline(mut self) -> Self {
+        self.style = Style::Sparkline;
+        self
+    }
+
+    /// Renders comma-separated values, with a header line.
+    pub fn csv(mut self) -> Self {
+        self.style = Style::Delimited(',');
+        self
+    }
+
+    /// Renders tab-separated values, with a header line.
+    pub fn tsv(mut self) -> Self {
+        self.style = Style::Delimited('\t');
+        self
+    }
+
+    fn buckets(&self) -> Vec<(&T, C)> {
+        let mut buckets: Vec<_> = self.hist.map.iter().map(|(t, &c)| (t, c)).collect();
+        match self.order {
+            Order::Key => buckets.sort(),
+            Order::Size => buckets.sort_by(|(a, x), (b, y)| y.cmp(x).then(a.cmp(b))),
+        }
+        buckets
+    }
+
+    /// The fraction of the largest bucket this one is, adjusted for scale.
+    fn fraction(&self, val: C, max: C) -> f64 {
+        match self.scale {
+            Scale::Linear => val.as_f64() / max.as_f64(),
+            Scale::Log => val.as_f64().ln_1p() / max.as_f64().ln_1p(),
+        }
+    }
+
+    fn bars(&self, f: &mut fmt::Formatter<'_>, buckets: &[(&T, C)], max: C) -> fmt::Result {
+        let width = self.width.unwrap_or_else(|| {
+            let top = max.as_f64() as usize;
+            if top >= 70 {
+                70
+            } else if top < 8 {
+                top * 4
+            } else if top < 12 {
+                top * 3
+            } else if top < 20 {
+                top * 2
+            } else {
+                top
+            }
+        });
+        let buckets: Vec<_> = buckets
+            .iter()
+            .map(|&(b, val)| {
+                let len = (self.fraction(val, max) * width as f64).ceil() as usize;
+                (format!("{b:?}"), len)
+            })
+            .collect();
+        let len = buckets.iter().map(|(lbl, _)| lbl.len()).max().unwrap();
+        let bar = format!("{:>len$} +-{}-", "", "-".repeat(width));
+        let max = match self.scale {
+            Scale::Linear => max.to_string(),
+            Scale::Log => format!("{max} (log)"),
+        };
+        let scale = format!("{:>len$} | 0{max:>1$}", "", width - 1);
+        if buckets.len() > 8 {
+            writeln!(f, "{scale}")?;
+            writeln!(f, "{bar}")?;
+        }
+        for (lbl, val) in &buckets {
+            writeln!(f, "{lbl:>len$} | {}", "#".repeat(*val))?;
+        }
+        writeln!(f, "{bar}")?;
+        writeln!(f, "{scale}")
+    }
+
+    fn sparkline_chars(&self, buckets: &[(&T, C)], max: C) -> String {
+        buckets
+            .iter()
+            .map(|&(_, val)| {
+                let i = (self.fraction(val, max) * SPARKS.len() as f64).ceil() as usize;
+                SPARKS[i.clamp(1, SPARKS.len()) - 1]
+            })
+            .collect()
+    }
+}
+
+/// The field quoted as RFC 4180 has it, if it contains the separator, a quote
+/// or a line break, such as a tuple or string key.
+fn quoted(field: &str, sep: char) -> Cow<'_, str> {
+    if field.contains([sep, '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Note that some dubious rounding choices are made around bar widths, as the
+/// idea is to give a quick summary of values, not render a verifiable chart. If
+/// you need the latter, do your own drawing.
+impl<T, C> Display for Render<'_, T, C>
+where
+    T: Debug + Eq + Hash + Ord,
+    C: Count,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buckets = self.buckets();
+        if let Style::Delimited(sep) = self.style {
+            writeln!(f, "bucket{sep}count")?;
+            for (b, val) in buckets {
+                writeln!(f, "{}{sep}{val}", quoted(&format!("{b:?}"), sep))?;
+            }
+            return Ok(());
+        }
+        // nothing to scale bars against, unless some bucket is positive
+        let Some(&max) = self.hist.map.values().max().filter(|&&m| m > C::default()) else {
+            return Ok(());
+        };
+        match self.style {
+            Style::Sparkline => f.write_str(&self.sparkline_chars(&buckets, max)),
+            _ => self.bars(f, &buckets, max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hist::IntoHistogram;
+
+    #[test]
+    fn bars() {
+        let hist = vec![3, 1, 2, 2].into_histogram();
+        assert_eq!(
+            "2 | ########\n1 | ####\n3 | ####\n  +----------\n  | 0      2\n",
+            hist.render().to_string()
+        );
+        assert_eq!(format!("{hist:?}"), hist.render().to_string());
+        assert_eq!(
+            "1 | ##\n2 | ####\n3 | ##\n  +------\n  | 0  2\n",
+            hist.render().by_key().width(4).to_string()
+        );
+    }
+
+    #[test]
+    fn log_scale() {
+        let mut hist = vec!['a'].into_histogram();
+        hist.add('b', 999);
+        let linear = hist.render().width(10).to_string();
+        assert!(linear.contains("'a' | #\n"), "{linear}");
+        let log = hist.render().width(10).log_scale().to_string();
+        assert!(log.contains("'a' | ##\n"), "{log}");
+        assert!(log.contains("'b' | ##########\n"), "{log}");
+        assert!(log.ends_with("999 (log)\n"), "{log}");
+    }
+
+    #[test]
+    fn sparkline() {
+        let hist: Histogram<_, u64> = (0..8).flat_map(|i| vec![i; i + 1]).collect();
+        assert_eq!("▁▂▃▄▅▆▇█", hist.render().by_key().sparkline().to_string());
+        assert_eq!("█▇▆▅▄▃▂▁", hist.render().sparkline().to_string());
+        assert_eq!("", Histogram::<u8>::new().render().sparkline().to_string());
+    }
+
+    #[test]
+    fn empty_buckets() {
+        let mut hist = Histogram::new();
+        hist.add('a', 0);
+        assert_eq!("", format!("{hist:?}"));
+        assert_eq!("", hist.render().sparkline().to_string());
+        assert_eq!("bucket,count\n'a',0\n", hist.render().csv().to_string());
+        let mut hist = Histogram::<char, i64>::default();
+        hist.add('a', -3);
+        hist.add('b', -1);
+        assert_eq!("", format!("{hist:?}"));
+        assert_eq!("", hist.render().sparkline().to_string());
+        assert_eq!(
+            "bucket,count\n'b',-1\n'a',-3\n",
+            hist.render().csv().to_string()
+        );
+    }
+
+    #[test]
+    fn delimited() {
+        let hist = vec!["cow", "rabbit", "rabbit"].into_histogram();
+        assert_eq!(
+            "bucket\tcount\n\"\"\"cow\"\"\"\t1\n\"\"\"rabbit\"\"\"\t2\n",
+            hist.render().by_key().tsv().to_string()
+        );
+        let hist = vec![(1, 2), (1, 2), (3, 4)].into_histogram();
+        assert_eq!(
+            "bucket,count\n\"(1, 2)\",2\n\"(3, 4)\",1\n",
+            hist.render().csv().to_string()
+        );
+        assert_eq!(
+            "bucket\tcount\n(1, 2)\t2\n(3, 4)\t1\n",
+            hist.render().tsv().to_string()
+        );
+        assert_eq!(
+            "bucket,count\n",
+            Histogram::<u8>::new().render().csv().to_string()
+        );
+    }
+}
@@ -1,10 +1,18 @@
+pub mod answers;
 pub mod aocd;
+pub mod batch;
+pub mod bench;
 pub mod block_print;
+pub mod cons;
 pub mod driver;
 #[macro_use]
 pub mod ord;
 pub mod geom2d;
+pub mod graph;
+pub mod grid;
 pub mod hist;
+pub mod registry;
+pub mod search;
 pub mod timing;
 pub mod viz;
 pub mod y2015;
@@ -0,0 +1,207 @@
+//! A registry of every solved day, so a single binary can dispatch to any of
+//! them. `new_day.py` adds new days to the table; keep it in year/day order.
+use crate::{with_input, Part};
+use std::io;
+use std::sync::mpsc::Sender;
+
+/// The signature every day's `do_solve` shares.
+pub type Solver = fn(&str, Sender<Part>);
+
+/// A registered day, and how to solve it.
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub year: u32,
+    pub day: u8,
+    /// The name of the solver's module, e.g., `historian_hysteria_01`.
+    pub name: &'static str,
+    pub solve: Solver,
+    /// An alternate solver for the same day, if there is one.
+    pub alternate: Option<Alternate>,
+}
+
+/// A second solver for an already-registered day.
+#[derive(Clone, Copy, Debug)]
+pub struct Alternate {
+    pub name: &'static str,
+    pub solve: Solver,
+}
+
+impl Solution {
+    /// Runs the solver via [with_input], with its answers printed and verified.
+    pub fn run(&self) -> io::Result<()> {
+        with_input(self.year, self.day, self.solve)
+    }
+}
+
+/// Each line is `year day module;`, optionally with `| alternate_module` before
+/// the semicolon. Modules are relative to the year's module.
+macro_rules! solutions {
+    ($($year:literal $day:literal $y:ident::$module:ident $(| $alt:ident)?;)*) => {
+        &[$(Solution {
+            year: $year,
+            day: $day,
+            name: stringify!($module),
+            solve: crate::$y::$module::do_solve,
+            alternate: solutions!(@alt $y $($alt)?),
+        },)*]
+    };
+    (@alt $y:ident) => { None };
+    (@alt $y:ident $alt:ident) => {
+        Some(Alternate {
+            name: stringify!($alt),
+            solve: crate::$y::$alt::do_solve,
+        })
+    };
+}
+
+#[rustfmt::skip]
+static SOLUTIONS: &[Solution] = solutions! {
+    2015  1 y2015::not_quite_lisp_01;
+    2015  6 y2015::probably_a_fire_hazard_06;
+    2015 16 y2015::aunt_sue_16;
+    2016  1 y2016::no_time_for_a_taxicab_01;
+    2016 11 y2016::radioisotope_thermoelectric_generators_11 | radioisotope_thermoelectric_generators_11_again;
+    2016 12 y2016::leonardos_monorail_12;
+    2016 13 y2016::a_maze_of_twisty_little_cubicles_13;
+    2016 19 y2016::an_elephant_named_joseph_19;
+    2017  1 y2017::inverse_captcha_01;
+    2017  2 y2017::corruption_checksum_02;
+    2017 23 y2017::coprocessor_conflagration_23;
+    2018  5 y2018::alchemical_reduction_05;
+    2018 10 y2018::the_stars_align_10;
+    2018 17 y2018::reservoir_research_17;
+    2019  2 y2019::_1202_program_alarm_02;
+    2019  8 y2019::space_image_format_08;
+    2019 14 y2019::space_stoichiometry_14;
+    2020  1 y2020::report_repair_01;
+    2020  4 y2020::passport_processing_04;
+    2020 11 y2020::seating_system_11;
+    2020 18 y2020::operation_order_18;
+    2020 22 y2020::crab_combat_22;
+    2021  1 y2021::sonar_sweep_01;
+    2021  2 y2021::dive_02;
+    2021  7 y2021::the_treachery_of_whales_07;
+    2022  1 y2022::calorie_counting_01;
+    2022  9 y2022::rope_bridge_09;
+    2022 13 y2022::distress_signal_13;
+    2022 20 y2022::grove_positioning_system_20;
+    2023  1 y2023::trebuchet_01;
+    2023  3 y2023::gear_ratios_03;
+    2023 15 y2023::lens_library_15;
+    2023 23 y2023::a_long_walk_23;
+    2024  1 y2024::historian_hysteria_01;
+    2024  2 y2024::red_nosed_reports_02;
+    2024  3 y2024::mull_it_over_03;
+    2024  4 y2024::ceres_search_04;
+    2024  5 y2024::print_queue_05;
+    2024  6 y2024::guard_gallivant_06;
+    2024  7 y2024::bridge_repair_07;
+    2024  8 y2024::resonant_collinearity_08;
+    2024  9 y2024::disk_fragmenter_09;
+    2024 10 y2024::hoof_it_10;
+    2024 11 y2024::plutonian_pebbles_11;
+    2024 12 y2024::garden_groups_12 | garden_groups_12b;
+    2024 13 y2024::claw_contraption_13;
+    2024 14 y2024::restroom_redoubt_14;
+    2024 15 y2024::warehouse_woes_15;
+    2024 16 y2024::reindeer_maze_16;
+    2024 17 y2024::chronospatial_computer_17;
+    2024 18 y2024::ram_run_18;
+    2024 19 y2024::linen_layout_19;
+    2024 20 y2024::race_condition_20;
+    2024 21 y2024::keypad_conundrum_21;
+    2024 22 y2024::monkey_market_22;
+    2024 23 y2024::lan_party_23;
+    2024 24 y2024::crossed_wires_24;
+    2024 25 y2024::code_chronicle_25;
+    // new_day.py adds days above this line
+};
+
+/// Every registered day, in year/day order.
+pub fn all() -> &'static [Solution] {
+    SOLUTIONS
+}
+
+/// The registered days for the given year, in day order.
+pub fn for_year(year: u32) -> impl Iterator<Item = &'static Solution> {
+    SOLUTIONS.iter().filter(move |s| s.year == year)
+}
+
+/// The registered solution for the given year/day, if there is one.
+pub fn find(year: u32, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.year == year && s.day == day)
+}
+
+/// Selects solutions from command line-style arguments: `all`, a year, or a
+/// year and day.
+pub fn select<S: AsRef<str>>(args: &[S]) -> Result<Vec<&'static Solution>, String> {
+    let num = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| format!("Can't interpret '{s}' as a number?!"))
+    };
+    match args {
+        [a] if a.as_ref() == "all" => Ok(SOLUTIONS.iter().collect()),
+        [y] => {
+            let year = num(y.as_ref())?;
+            let solutions: Vec<_> = for_year(year).collect();
+            if solutions.is_empty() {
+                Err(format!("No days of {year} are solved"))
+            } else {
+                Ok(solutions)
+            }
+        }
+        [y, d] => {
+            let (year, day) = (num(y.as_ref())?, num(d.as_ref())?);
+            u8::try_from(day)
+                .ok()
+                .and_then(|day| find(year, day))
+                .map(|s| vec![s])
+                .ok_or_else(|| format!("{year} day {day} isn't solved"))
+        }
+        _ => Err("Expected 'all', a year, or a year and day".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn in_order() {
+        for w in SOLUTIONS.windows(2) {
+            assert!(
+                (w[0].year, w[0].day) < (w[1].year, w[1].day),
+                "{} should be after {}",
+                w[0].name,
+                w[1].name
+            );
+        }
+    }
+
+    #[test]
+    fn names_match_days() {
+        for s in SOLUTIONS {
+            assert!(s.name.contains(&format!("_{:02}", s.day)), "{}", s.name);
+        }
+    }
+
+    #[test]
+    fn lookups() {
+        let s = find(2024, 12).unwrap();
+        assert_eq!("garden_groups_12", s.name);
+        assert_eq!("garden_groups_12b", s.alternate.unwrap().name);
+        assert!(find(2024, 26).is_none());
+        assert_eq!(25, for_year(2024).count());
+    }
+
+    #[test]
+    fn selections() {
+        assert_eq!(SOLUTIONS.len(), select(&["all"]).unwrap().len());
+        assert_eq!(25, select(&["2024"]).unwrap().len());
+        assert_eq!("ram_run_18", select(&["2024", "18"]).unwrap()[0].name);
+        assert!(select(&["2024", "26"]).is_err());
+        assert!(select(&["2014"]).is_err());
+        assert!(select(&["twenty"]).is_err());
+        assert!(select::<&str>(&[]).is_err());
+    }
+}
@@ -0,0 +1,492 @@
+//! Generic graph searches over a successor function: breadth-first for unit
+//! costs, Dijkstra for non-negative costs, and A* for Dijkstra with a
+//! heuristic. Each returns a [Search], recording every reached node's distance
+//! from the start, and every predecessor along a shortest path to it. Together
+//! those form a DAG of all shortest paths, for recovering one path, or counting
+//! or enumerating every tied-best path.
+//!
+//! ```
+//! # use aoc::search::bfs_to;
+//! // the ways to reach 10 from 1, by doubling or adding one
+//! let search = bfs_to(1, |&n| [n * 2, n + 1], |&n| n == 10);
+//!
+//! assert_eq!(Some(4), search.cost());
+//! assert_eq!(Some(vec![1, 2, 4, 5, 10]), search.path_to(&10));
+//! ```
+//!
+//! Goal-directed searches stop once every goal at the best cost is found, so
+//! only those goals' DAGs are complete. With zero-cost edges, the DAG can't
+//! hold every tied path, as two nodes at the same distance may each lead to
+//! the other. Whichever tie is explored first is kept, and the other dropped.
+//! Costs must be non-negative, and a heuristic must be consistent (never
+//! decreasing by more than an edge's cost along it) for paths to be shortest.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of a search: distances and shortest-path predecessors of every
+/// reached node, and any goals found.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    start: N,
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new(start: N, zero: C) -> Search<N, C> {
+        Search {
+            distances: HashMap::from([(start.clone(), zero)]),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+            start,
+        }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The first goal found, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goals.first()
+    }
+
+    /// Every goal found, all at the same, best cost.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The cost to reach the goal(s), if any was found.
+    pub fn cost(&self) -> Option<C> {
+        self.goal().and_then(|g| self.distance(g))
+    }
+
+    /// The best cost found to reach a node, if it was reached.
+    pub fn distance(&self, n: &N) -> Option<C> {
+        self.distances.get(n).copied()
+    }
+
+    /// The best cost found to reach every reached node.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<N, C> {
+        self.distances
+    }
+
+    /// Every node immediately before this one on some shortest path to it.
+    /// Empty for the start, and unreached nodes. With zero-cost edges, ties
+    /// which would close a cycle are left out, so which are kept depends on
+    /// exploration order.
+    pub fn predecessors(&self, n: &N) -> &[N] {
+        self.predecessors.get(n).map_or(&[], |ps| ps.as_slice())
+    }
+
+    /// A shortest path from the start to the passed node, inclusive, or `None`
+    /// if it wasn't reached.
+    pub fn path_to(&self, n: &N) -> Option<Vec<N>> {
+        self.distances.get(n)?;
+        let mut path = vec![n.clone()];
+        while let Some(p) = self.predecessors(path.last().unwrap()).first() {
+            path.push(p.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on any shortest path from the start to the passed node,
+    /// inclusive. Empty if it wasn't reached.
+    pub fn on_paths_to(&self, n: &N) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        if !self.distances.contains_key(n) {
+            return seen;
+        }
+        let mut stack = vec![n.clone()];
+        while let Some(curr) = stack.pop() {
+            for p in self.predecessors(&curr) {
+                if !seen.contains(p) {
+                    stack.push(p.clone());
+                }
+            }
+            seen.insert(curr);
+        }
+        seen
+    }
+
+    /// The number of distinct shortest paths from the start to the passed
+    /// node, through the [predecessors](Self::predecessors). Zero if it wasn't
+    /// reached.
+    pub fn count_paths_to(&self, n: &N) -> usize {
+        if !self.distances.contains_key(n) {
+            return 0;
+        }
+        // count each node's predecessors before it, which distance alone can't
+        // order when zero-cost steps tie
+        let mut counts: HashMap<&N, usize> = HashMap::new();
+        let mut stack = vec![n];
+        while let Some(&curr) = stack.last() {
+            if counts.contains_key(curr) {
+                stack.pop();
+                continue;
+            }
+            let ps = self.predecessors(curr);
+            let pending: Vec<_> = ps.iter().filter(|p| !counts.contains_key(p)).collect();
+            if pending.is_empty() {
+                let count = match ps {
+                    [] => 1,
+                    ps => ps.iter().map(|p| counts[p]).sum(),
+                };
+                counts.insert(curr, count);
+                stack.pop();
+            } else {
+                stack.extend(pending);
+            }
+        }
+        counts[n]
+    }
+
+    /// Every distinct shortest path from the start to the passed node,
+    /// inclusive, through the [predecessors](Self::predecessors), so not every
+    /// one if zero-cost edges tied. Beware that there may be exponentially
+    /// many.
+    pub fn all_paths_to(&self, n: &N) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+        if !self.distances.contains_key(n) {
+            return paths;
+        }
+        // partial paths, backwards from the passed node
+        let mut stack = vec![vec![n.clone()]];
+        while let Some(path) = stack.pop() {
+            match self.predecessors(path.last().unwrap()) {
+                [] => {
+                    let mut path = path;
+                    path.reverse();
+                    paths.push(path);
+                }
+                ps => {
+                    for p in ps {
+                        let mut next = path.clone();
+                        next.push(p.clone());
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Records a better or tied way to reach `next` via `curr`, returning
+    /// whether it was better. Ties never give the start a predecessor, nor
+    /// close a cycle of zero-cost steps, so predecessors always form a DAG.
+    fn relax(&mut self, curr: &N, next: N, cost: C) -> bool {
+        match self.distances.get(&next) {
+            Some(&d) if d < cost => false,
+            Some(&d) if d == cost => {
+                if next != self.start && !self.leads_to(&next, curr, d) {
+                    let preds = self.predecessors.entry(next).or_default();
+                    if !preds.contains(curr) {
+                        preds.push(curr.clone());
+                    }
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![curr.clone()]);
+                true
+            }
+        }
+    }
+
+    /// Whether `from` is `to`, or on a shortest path to it, via only nodes at
+    /// distance `d`. Only zero-cost steps join those, so only they can form a
+    /// cycle.
+    fn leads_to(&self, from: &N, to: &N, d: C) -> bool {
+        if self.distances.get(to) != Some(&d) {
+            return false;
+        }
+        let mut seen = HashSet::from([to]);
+        let mut stack = vec![to];
+        while let Some(curr) = stack.pop() {
+            if curr == from {
+                return true;
+            }
+            for p in self.predecessors(curr) {
+                if self.distances[p] == d && seen.insert(p) {
+                    stack.push(p);
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Breadth-first search of every node reachable from the start, where each
+/// step costs one.
+pub fn bfs<N, S, I>(start: N, successors: S) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    bfs_to(start, successors, |_| false)
+}
+
+/// Breadth-first search from the start until the nearest goal(s) are found,
+/// where each step costs one.
+pub fn bfs_to<N, S, I, G>(start: N, mut successors: S, mut is_goal: G) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut found = None;
+    while let Some((curr, dist)) = queue.pop_front() {
+        if let Some(best) = found {
+            if dist > best {
+                break;
+            }
+        }
+        if is_goal(&curr) {
+            found = Some(dist);
+            search.goals.push(curr);
+            continue;
+        }
+        if found.is_some() {
+            // nothing further can be on a path to a goal at this distance
+            continue;
+        }
+        for next in successors(&curr) {
+            if search.relax(&curr, next.clone(), dist + 1) {
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, searching every node reachable from the start.
+/// Successors are paired with the cost of the step to them.
+pub fn dijkstra<N, C, S, I>(start: N, successors: S) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Add<Output = C> + Copy + Default + Ord,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar_to(start, successors, |_| C::default(), |_| false)
+}
+
+/// Dijkstra's algorithm, searching from the start until the cheapest goal(s)
+/// are found. Successors are paired with the cost of the step to them.
+pub fn dijkstra_to<N, C, S, I, G>(start: N, successors: S, is_goal: G) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Add<Output = C> + Copy + Default + Ord,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar_to(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search from the start until the cheapest goal(s) are found, exploring
+/// nodes in order of their cost so far plus the heuristic's estimate of their
+/// remaining cost. Successors are paired with the cost of the step to them.
+///
+/// ```
+/// # use aoc::search::astar_to;
+/// let goal = (5, 3);
+/// let search = astar_to(
+///     (0, 0),
+///     |&(x, y)| [((x + 1, y), 1), ((x, y + 1), 1)],
+///     |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+///     |&p| p == goal,
+/// );
+///
+/// assert_eq!(Some(8), search.cost());
+/// assert_eq!(56, search.count_paths_to(&goal));
+/// ```
+pub fn astar_to<N, C, S, I, H, G>(
+    start: N,
+    mut successors: S,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Add<Output = C> + Copy + Default + Ord,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    // nodes live in a vec, so the heap needn't order them
+    let mut nodes = vec![start];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&nodes[0]), C::default(), 0))]);
+    let mut found = None;
+    while let Some(Reverse((priority, dist, idx))) = queue.pop() {
+        if let Some(best) = found {
+            if priority > best {
+                break;
+            }
+        }
+        let curr = nodes[idx].clone();
+        if search.distances[&curr] < dist {
+            // already reached more cheaply
+            continue;
+        }
+        if is_goal(&curr) {
+            found = Some(priority);
+            search.goals.push(curr);
+            continue;
+        }
+        // with a heuristic, nodes tied with a goal may still precede it
+        for (next, cost) in successors(&curr) {
+            let dist = dist + cost;
+            if search.relax(&curr, next.clone(), dist) {
+                queue.push(Reverse((dist + heuristic(&next), dist, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E";
+
+    fn open(p: (usize, usize)) -> bool {
+        MAZE.lines()
+            .nth(p.1)
+            .and_then(|l| l.chars().nth(p.0))
+            .is_some_and(|c| c != '#')
+    }
+
+    fn steps((x, y): &(usize, usize)) -> Vec<(usize, usize)> {
+        [
+            (x + 1, *y),
+            (*x, y + 1),
+            (x.wrapping_sub(1), *y),
+            (*x, y.wrapping_sub(1)),
+        ]
+        .into_iter()
+        .filter(|&p| open(p))
+        .collect()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let search = bfs_to((0, 0), steps, |&p| p == (3, 2));
+        assert_eq!(Some(&(3, 2)), search.goal());
+        assert_eq!(Some(5), search.cost());
+        assert_eq!(Some(6), search.path_to(&(3, 2)).map(|p| p.len()));
+        assert_eq!(3, search.count_paths_to(&(3, 2)));
+        assert_eq!(3, search.all_paths_to(&(3, 2)).len());
+        assert_eq!(10, search.on_paths_to(&(3, 2)).len());
+
+        let all = bfs((0, 0), steps);
+        assert_eq!(None, all.goal());
+        assert_eq!(10, all.distances().len());
+        assert_eq!(Some(4), all.distance(&(3, 1)));
+        assert_eq!(None, all.distance(&(3, 0)));
+        assert_eq!(0, all.count_paths_to(&(3, 0)));
+        assert!(all.predecessors(&(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn tied_goals() {
+        let search = bfs_to(0, |&n| [n + 1, n + 2], |&n| n >= 3);
+        let mut goals = search.goals().to_vec();
+        goals.sort();
+        assert_eq!(vec![3, 4], goals);
+        assert_eq!(Some(2), search.cost());
+    }
+
+    #[test]
+    fn weighted() {
+        // going right is cheap, but down is dear
+        let search = dijkstra_to(
+            (0, 0),
+            |&p| {
+                steps(&p)
+                    .into_iter()
+                    .map(move |q| (q, if q.1 > p.1 { 5 } else { 1 }))
+            },
+            |&p| p == (0, 2),
+        );
+        assert_eq!(Some(10), search.cost());
+        let all = dijkstra((0, 0), |&p| steps(&p).into_iter().map(|q| (q, 2u64)));
+        assert_eq!(Some(10), all.distance(&(3, 2)));
+        assert_eq!(3, all.count_paths_to(&(3, 2)));
+    }
+
+    #[test]
+    fn heuristic_ties() {
+        let goal = (3, 2);
+        let search = astar_to(
+            (0, 0),
+            |p| steps(p).into_iter().map(|q| (q, 1)),
+            |&(x, y)| goal.0 - x + goal.1 - y,
+            |&p| p == goal,
+        );
+        assert_eq!(Some(5), search.cost());
+        assert_eq!(3, search.count_paths_to(&goal));
+    }
+
+    #[test]
+    fn zero_costs() {
+        let search = dijkstra(0, |&n| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0)],
+            _ => vec![],
+        });
+        assert!(search.predecessors(&0).is_empty());
+        assert_eq!(Some(vec![0, 1]), search.path_to(&1));
+        assert_eq!(1, search.all_paths_to(&1).len());
+
+        // ties between nodes at the same distance, in both directions
+        let edges = [
+            (0, 1, 0),
+            (0, 2, 1),
+            (1, 2, 1),
+            (2, 3, 0),
+            (3, 4, 0),
+            (4, 3, 0),
+            (2, 4, 0),
+        ];
+        let successors = |&n: &i32| {
+            edges
+                .iter()
+                .filter(move |e| e.0 == n)
+                .map(|&(_, b, c)| (b, c))
+        };
+        for _ in 0..20 {
+            let search = dijkstra(0, successors);
+            assert_eq!(Some(1), search.distance(&4));
+            assert_eq!(2, search.count_paths_to(&3));
+            // via 1 or not, then via 3 or not; 3 is explored before 4, so it
+            // keeps the tie with 4 and 4 doesn't
+            assert_eq!(4, search.count_paths_to(&4));
+            assert_eq!(search.count_paths_to(&4), search.all_paths_to(&4).len());
+        }
+    }
+}
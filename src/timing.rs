@@ -19,10 +19,14 @@
 //!
 //! Finally, there is [ad_hoc](Timing::ad_hoc) for, shockingly, ad hoc use. Pass
 //! it a label and procedure, it'll print timing to STDOUT.
+//!
+//! For nested, recursive, or multithreaded code, see [profile].
 #![allow(dead_code)]
 use std::cell::Cell;
 use std::time::{Duration, Instant};
 
+pub mod profile;
+
 #[derive(Debug, Default)]
 pub struct Timing {
     count: Cell<usize>,
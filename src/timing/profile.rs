@@ -0,0 +1,391 @@
+//! Named, nestable spans, for seeing where a solver spends its time. Unlike a
+//! bare [Timing], spans may be nested and recursed into, and used from any
+//! thread. Each span's call count, total time, and self time (total time less
+//! that of its child spans) is recorded under its path from the root span.
+//!
+//! ```
+//! # use aoc::timing::profile::{self, span};
+//! fn fib(n: u64) -> u64 {
+//!     let _s = span("fib");
+//!     if n < 2 {
+//!         n
+//!     } else {
+//!         fib(n - 1) + fib(n - 2)
+//!     }
+//! }
+//!
+//! profile::scope("solve", || fib(10));
+//! let nodes = profile::take();
+//! assert_eq!(vec!["solve"], nodes[0].path);
+//! assert_eq!(vec!["solve", "fib"], nodes[1].path);
+//! assert_eq!(177, nodes[1].calls);
+//! ```
+//!
+//! Direct recursion is folded into a single node, so `fib` above doesn't nest
+//! 10 deep. Its total time is that of the outermost call, and its call count
+//! includes every recursive call.
+//!
+//! Spans are buffered per-thread, and merged into the shared registry each
+//! time a thread's outermost span exits, unless the thread is inside a
+//! [capture]. The driver captures each solver's own thread, so solvers run in
+//! parallel are profiled separately, and reports each profile (along with the
+//! registry, for threads solvers spawn themselves) as directed by the
+//! [PROFILE_VAR] environment variable.
+use super::Timing;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Environment variable controlling profile output after each solver, from
+/// [with_input](crate::with_input) or [report](crate::driver::report). If
+/// `tree`, the profile is printed as an indented tree. Otherwise it names a
+/// file to write folded stacks to, for use with `flamegraph.pl` or
+/// `inferno-flamegraph`.
+pub const PROFILE_VAR: &str = "BEB_PROFILE";
+
+type SpanPath = Vec<&'static str>;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Stats {
+    calls: usize,
+    total: Duration,
+    self_time: Duration,
+}
+
+impl Stats {
+    fn merge(&mut self, other: &Stats) {
+        self.calls += other.calls;
+        self.total += other.total;
+        self.self_time += other.self_time;
+    }
+}
+
+static REGISTRY: Mutex<BTreeMap<SpanPath, Stats>> = Mutex::new(BTreeMap::new());
+
+struct Frame {
+    name: &'static str,
+    timing: Timing,
+    /// How many direct recursions deep this frame currently is.
+    depth: usize,
+    calls: usize,
+    child_time: Duration,
+}
+
+#[derive(Default)]
+struct Local {
+    stack: Vec<Frame>,
+    buffer: BTreeMap<SpanPath, Stats>,
+    /// Where outermost spans are merged instead of the registry, in a
+    /// [capture].
+    captured: Option<BTreeMap<SpanPath, Stats>>,
+}
+
+fn merge_into(into: &mut BTreeMap<SpanPath, Stats>, from: BTreeMap<SpanPath, Stats>) {
+    for (path, stats) in from {
+        into.entry(path).or_default().merge(&stats);
+    }
+}
+
+thread_local! {
+    static LOCAL: RefCell<Local> = RefCell::new(Local::default());
+}
+
+/// An open span, which is closed when dropped. Must be dropped on the thread
+/// which opened it.
+#[must_use = "the span closes as soon as it's dropped"]
+pub struct Span {
+    name: &'static str,
+    _not_send: PhantomData<*const ()>,
+}
+
+/// Opens a span with the given name, nested inside the thread's current span,
+/// if there is one.
+pub fn span(name: &'static str) -> Span {
+    LOCAL.with_borrow_mut(|local| match local.stack.last_mut() {
+        Some(top) if top.name == name => {
+            top.depth += 1;
+            top.calls += 1;
+        }
+        _ => {
+            let timing = Timing::default();
+            timing.enter();
+            local.stack.push(Frame {
+                name,
+                timing,
+                depth: 0,
+                calls: 1,
+                child_time: Duration::ZERO,
+            })
+        }
+    });
+    Span {
+        name,
+        _not_send: PhantomData,
+    }
+}
+
+/// Applies the passed function inside a span with the given name.
+pub fn scope<T, W>(name: &'static str, function: W) -> T
+where
+    W: FnOnce() -> T,
+{
+    let _s = span(name);
+    function()
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        LOCAL.with_borrow_mut(|local| {
+            let top = local
+                .stack
+                .last_mut()
+                .expect("A span should be open on this thread");
+            assert_eq!(self.name, top.name, "Spans must close in order");
+            if top.depth > 0 {
+                top.depth -= 1;
+                return;
+            }
+            let frame = local.stack.pop().unwrap();
+            frame.timing.exit();
+            let total = frame.timing.total_time();
+            let mut path: SpanPath = local.stack.iter().map(|f| f.name).collect();
+            if let Some(parent) = local.stack.last_mut() {
+                parent.child_time += total;
+            }
+            path.push(frame.name);
+            local.buffer.entry(path).or_default().merge(&Stats {
+                calls: frame.calls,
+                total,
+                self_time: total.saturating_sub(frame.child_time),
+            });
+            if local.stack.is_empty() {
+                let buffer = std::mem::take(&mut local.buffer);
+                match &mut local.captured {
+                    Some(captured) => merge_into(captured, buffer),
+                    None => merge_into(&mut REGISTRY.lock().unwrap(), buffer),
+                }
+            }
+        })
+    }
+}
+
+/// A recorded span, and every call to it along the same path.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Node {
+    /// The names of the enclosing spans, outermost first, ending with this
+    /// span's own.
+    pub path: Vec<&'static str>,
+    pub calls: usize,
+    pub total: Duration,
+    /// Total time, less that spent in child spans.
+    pub self_time: Duration,
+}
+
+fn to_nodes(stats: BTreeMap<SpanPath, Stats>) -> Vec<Node> {
+    stats
+        .into_iter()
+        .map(|(path, s)| Node {
+            path,
+            calls: s.calls,
+            total: s.total,
+            self_time: s.self_time,
+        })
+        .collect()
+}
+
+/// Removes and returns everything recorded in the shared registry so far, with
+/// each node following its parent, and siblings in name order.
+pub fn take() -> Vec<Node> {
+    to_nodes(std::mem::take(&mut *REGISTRY.lock().unwrap()))
+}
+
+/// Applies the passed function, returning what it recorded on this thread
+/// separately from the shared registry, ordered as by [take]. Spans on threads
+/// it spawns still go to the registry. If it panics, its spans are lost.
+pub fn capture<T, W>(function: W) -> (T, Vec<Node>)
+where
+    W: FnOnce() -> T,
+{
+    let outer = LOCAL.with_borrow_mut(|local| local.captured.replace(BTreeMap::new()));
+    let t = function();
+    let captured = LOCAL.with_borrow_mut(|local| std::mem::replace(&mut local.captured, outer));
+    (t, to_nodes(captured.unwrap_or_default()))
+}
+
+/// Combines nodes recorded separately, such as a [capture] and the registry
+/// afterward, ordered as by [take].
+pub fn merge(a: Vec<Node>, b: Vec<Node>) -> Vec<Node> {
+    let mut stats = BTreeMap::new();
+    for n in a.into_iter().chain(b) {
+        let s = Stats {
+            calls: n.calls,
+            total: n.total,
+            self_time: n.self_time,
+        };
+        stats.entry(n.path).or_insert_with(Stats::default).merge(&s);
+    }
+    to_nodes(stats)
+}
+
+/// Renders nodes, as from [take], as an indented tree with a line per node.
+pub fn tree(nodes: &[Node]) -> String {
+    let width = nodes
+        .iter()
+        .map(|n| (n.path.len() - 1) * 2 + n.path.last().unwrap().len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let mut s = format!(
+        "{:width$} | {:>10} | {:>12} | {:>12}\n",
+        "span", "calls", "total", "self"
+    );
+    for n in nodes {
+        let name = format!(
+            "{:indent$}{}",
+            "",
+            n.path.last().unwrap(),
+            indent = (n.path.len() - 1) * 2
+        );
+        writeln!(
+            s,
+            "{name:width$} | {:>10} | {:>12?} | {:>12?}",
+            n.calls, n.total, n.self_time
+        )
+        .unwrap();
+    }
+    s
+}
+
+/// Renders nodes, as from [take], as folded stacks: a line per node with its
+/// `;`-separated path and self time in nanoseconds.
+pub fn folded(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|n| format!("{} {}\n", n.path.join(";"), n.self_time.as_nanos()))
+        .collect()
+}
+
+/// Reports the passed nodes, as from [take] or [capture], as directed by
+/// [PROFILE_VAR]. Does nothing if it's unset, or there are no nodes.
+pub fn report(nodes: &[Node]) -> io::Result<()> {
+    let Ok(dest) = std::env::var(PROFILE_VAR) else {
+        return Ok(());
+    };
+    if nodes.is_empty() {
+        return Ok(());
+    }
+    if dest == "tree" {
+        print!("{}", tree(nodes));
+        Ok(())
+    } else {
+        fs::write(Path::new(&dest), folded(nodes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::thread;
+
+    // the registry is shared, so look at what a fresh thread buffered instead,
+    // under an outer span which keeps it from being merged
+    fn on_thread<F: FnOnce() + Send + 'static>(f: F) -> BTreeMap<SpanPath, Stats> {
+        thread::spawn(move || {
+            let _t = span("thread");
+            f();
+            LOCAL.with_borrow(|local| local.buffer.clone())
+        })
+        .join()
+        .unwrap()
+    }
+
+    #[test]
+    fn nesting() {
+        let buffer = on_thread(|| {
+            let _a = span("a");
+            {
+                let _b = span("b");
+                thread::sleep(Duration::from_millis(10));
+            }
+            let _b = span("b");
+        });
+        let b = buffer[&vec!["thread", "a", "b"]];
+        let a = buffer[&vec!["thread", "a"]];
+        assert_eq!(2, b.calls);
+        assert_eq!(1, a.calls);
+        assert!(a.total >= b.total);
+        assert!(b.self_time >= Duration::from_millis(10));
+        assert_eq!(a.total - b.total, a.self_time);
+    }
+
+    #[test]
+    fn recursion() {
+        fn down(n: usize) {
+            let _s = span("down");
+            if n > 0 {
+                scope("side", || {});
+                down(n - 1);
+            }
+        }
+        let buffer = on_thread(|| down(5));
+        let paths: Vec<_> = buffer.keys().cloned().collect();
+        assert_eq!(
+            vec![vec!["thread", "down"], vec!["thread", "down", "side"]],
+            paths
+        );
+        assert_eq!(6, buffer[&vec!["thread", "down"]].calls);
+        assert_eq!(5, buffer[&vec!["thread", "down", "side"]].calls);
+    }
+
+    #[test]
+    fn captured() {
+        let (n, nodes) = thread::spawn(|| capture(|| scope("captured", || 7)))
+            .join()
+            .unwrap();
+        assert_eq!(7, n);
+        assert_eq!(1, nodes.len());
+        assert_eq!(vec!["captured"], nodes[0].path);
+        assert!(!take().iter().any(|n| n.path == vec!["captured"]));
+        let both = merge(nodes.clone(), nodes);
+        assert_eq!(1, both.len());
+        assert_eq!(2, both[0].calls);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_order() {
+        let a = span("a");
+        let _b = span("b");
+        drop(a);
+    }
+
+    #[test]
+    fn rendering() {
+        let nodes = vec![
+            Node {
+                path: vec!["solve"],
+                calls: 1,
+                total: Duration::from_nanos(300),
+                self_time: Duration::from_nanos(100),
+            },
+            Node {
+                path: vec!["solve", "parse"],
+                calls: 2,
+                total: Duration::from_nanos(200),
+                self_time: Duration::from_nanos(200),
+            },
+        ];
+        assert_eq!("solve 100\nsolve;parse 200\n", folded(&nodes));
+        let tree = tree(&nodes);
+        let lines: Vec<_> = tree.lines().collect();
+        assert_eq!(3, lines.len());
+        assert!(lines[1].starts_with("solve   |"), "{tree}");
+        assert!(lines[2].starts_with("  parse |"), "{tree}");
+    }
+}
@@ -1,6 +1,11 @@
 use crate::Part;
 use std::sync::mpsc::Sender;
 
+pub fn do_solve(input: &str, tx: Sender<Part>) {
+    tx.send(Part::A(part_one(input).to_string())).unwrap();
+    tx.send(Part::B(part_two(input).to_string())).unwrap();
+}
+
 pub fn part_one(input: &str) -> i32 {
     input.chars().fold(0, |f, c| match c {
         '(' => f + 1,
@@ -60,12 +65,7 @@ mod test {
 
     #[test]
     fn test_real_input() {
-        use crate::{with_input, Part};
-        with_input(2015, 1, |input, tx| {
-            tx.send(Part::A(part_one(input).to_string())).unwrap();
-            tx.send(Part::B(part_two(input).to_string())).unwrap();
-        })
-        .unwrap();
+        crate::with_input(2015, 1, do_solve).unwrap();
     }
 
     #[test]
@@ -1,6 +1,6 @@
 pub mod a_maze_of_twisty_little_cubicles_13;
 pub mod an_elephant_named_joseph_19;
 pub mod leonardos_monorail_12;
-mod no_time_for_a_taxicab_01;
+pub mod no_time_for_a_taxicab_01;
 pub mod radioisotope_thermoelectric_generators_11;
 pub mod radioisotope_thermoelectric_generators_11_again;
@@ -1,3 +1,11 @@
+use crate::Part;
+use std::sync::mpsc::Sender;
+
+pub fn do_solve(input: &str, tx: Sender<Part>) {
+    tx.send(Part::A(part_one(input).to_string())).unwrap();
+    tx.send(Part::B(part_two(input).to_string())).unwrap();
+}
+
 #[derive(Debug, Eq, PartialEq)]
 enum Val {
     A,
@@ -163,11 +171,6 @@ dec a"#;
 
     #[test]
     fn test_real_input() {
-        use crate::{with_input, Part};
-        with_input(2016, 12, |input, tx| {
-            tx.send(Part::A(part_one(input).to_string())).unwrap();
-            tx.send(Part::B(part_two(input).to_string())).unwrap();
-        })
-        .unwrap();
+        crate::with_input(2016, 12, do_solve).unwrap();
     }
 }
@@ -1,5 +1,12 @@
 #![allow(dead_code)]
+use crate::Part;
 use std::collections::HashSet;
+use std::sync::mpsc::Sender;
+
+pub fn do_solve(input: &str, tx: Sender<Part>) {
+    tx.send(Part::A(part_one(input).to_string())).unwrap();
+    tx.send(Part::B(part_two(input).to_string())).unwrap();
+}
 
 #[derive(Debug, Eq, PartialEq)]
 enum Heading {
@@ -108,11 +115,6 @@ mod test {
 
     #[test]
     fn test_real_input() {
-        use crate::{with_input, Part};
-        with_input(2016, 1, |input, tx| {
-            tx.send(Part::A(part_one(input).to_string())).unwrap();
-            tx.send(Part::B(part_two(input).to_string())).unwrap();
-        })
-        .unwrap();
+        crate::with_input(2016, 1, do_solve).unwrap();
     }
 }
@@ -1,3 +1,11 @@
+use crate::Part;
+use std::sync::mpsc::Sender;
+
+pub fn do_solve(input: &str, tx: Sender<Part>) {
+    tx.send(Part::A(part_one(input).to_string())).unwrap();
+    tx.send(Part::B(part_two(input).to_string())).unwrap();
+}
+
 pub fn part_one(input: &str) -> usize {
     let mut sum: usize = 0;
     for l in input.lines() {
@@ -63,11 +71,6 @@ mod test {
 
     #[test]
     fn test_real_input() {
-        use crate::{with_input, Part};
-        with_input(2017, 2, |input, tx| {
-            tx.send(Part::A(part_one(input).to_string())).unwrap();
-            tx.send(Part::B(part_two(input).to_string())).unwrap();
-        })
-        .unwrap();
+        crate::with_input(2017, 2, do_solve).unwrap();
     }
 }
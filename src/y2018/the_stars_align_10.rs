@@ -1,6 +1,5 @@
-use crate::block_print::{parse_block_letters, BLOCK};
+use crate::block_print::parse_block_points;
 use crate::Part;
-use std::collections::HashSet;
 use std::sync::mpsc::Sender;
 
 pub fn do_solve(input: &str, tx: Sender<Part>) {
@@ -22,29 +21,17 @@ fn both_parts(input: &str) -> (String, usize) {
         })
         .collect();
     let mut tick_count = 0;
-    let mut bounds = compute_bounds(&points);
-    let mut area = compute_area(&bounds);
+    let mut area = compute_area(&compute_bounds(&points));
     loop {
         let next = tick(&points);
-        let next_bounds = compute_bounds(&next);
-        let next_area = compute_area(&next_bounds);
+        let next_area = compute_area(&compute_bounds(&next));
         if next_area > area {
-            let (min, max) = bounds;
-            let pos: HashSet<_> = points.into_iter().map(|(p, _)| p).collect();
-            let mut buffer = String::new();
-            for y in min.1..=max.1 {
-                for x in min.0..=max.0 {
-                    buffer.push(if pos.contains(&(x, y)) { BLOCK } else { '.' });
-                }
-                buffer.push('\n');
-            }
-            println!("{buffer}");
-            break (parse_block_letters(&buffer).unwrap(), tick_count);
+            let pos = points.into_iter().map(|(p, _)| p);
+            break (parse_block_points(pos).unwrap(), tick_count);
         }
         tick_count += 1;
         points = next;
         area = next_area;
-        bounds = next_bounds;
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::block_print::{parse_block_letters, BLOCK};
+use crate::block_print::parse_block_bitmap;
 use crate::Part;
 use std::sync::mpsc::Sender;
 
@@ -45,11 +45,11 @@ fn part_two(layers: &Vec<Vec<char>>) -> String {
         for l in layers {
             match l[i] {
                 '0' => {
-                    pixels.push(' ');
+                    pixels.push(false);
                     break;
                 }
                 '1' => {
-                    pixels.push(BLOCK);
+                    pixels.push(true);
                     break;
                 }
                 '2' => continue, // transparent
@@ -57,12 +57,7 @@ fn part_two(layers: &Vec<Vec<char>>) -> String {
             }
         }
     }
-    let mut display = String::new();
-    for line in pixels.chunks(WIDTH) {
-        display.extend(line.iter());
-        display.push('\n')
-    }
-    parse_block_letters(&display).unwrap()
+    parse_block_bitmap(&pixels, WIDTH).unwrap()
 }
 
 #[cfg(test)]
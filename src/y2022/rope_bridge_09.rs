@@ -1,30 +1,46 @@
 use crate::geom2d::{step, Dir};
 use crate::Part;
+use anyhow::{anyhow, Context, Result};
 use std::collections::HashSet;
 use std::sync::mpsc::Sender;
 
 pub fn do_solve(input: &str, tx: Sender<Part>) {
-    tx.send(Part::A(part_one(input).to_string())).unwrap();
-    tx.send(Part::B(part_two(input).to_string())).unwrap();
+    let motions = parse(input).unwrap();
+    tx.send(Part::A(part_one(&motions).to_string())).unwrap();
+    tx.send(Part::B(part_two(&motions).to_string())).unwrap();
 }
 
 type Pt = (i32, i32);
 
-fn part_one(input: &str) -> usize {
-    either_part(input, 1)
+fn parse(input: &str) -> Result<Vec<(Dir, u32)>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let motion = || -> Result<_> {
+                let (dir, n) = line
+                    .split_once(' ')
+                    .ok_or_else(|| anyhow!("expected a direction and a distance"))?;
+                Ok((dir.parse()?, n.parse()?))
+            };
+            motion().with_context(|| format!("Bad motion '{line}' on line {}", i + 1))
+        })
+        .collect()
 }
 
-fn part_two(input: &str) -> usize {
-    either_part(input, 9)
+fn part_one(motions: &[(Dir, u32)]) -> usize {
+    either_part(motions, 1)
 }
 
-fn either_part(input: &str, tail_len: usize) -> usize {
+fn part_two(motions: &[(Dir, u32)]) -> usize {
+    either_part(motions, 9)
+}
+
+fn either_part(motions: &[(Dir, u32)], tail_len: usize) -> usize {
     let mut head: Pt = (0, 0);
     let mut trailing = vec![head; tail_len];
     let mut visited = HashSet::from([head]);
-    for line in input.lines() {
-        let dir: Dir = line.into();
-        let n: u32 = line[2..].parse().unwrap();
+    for &(dir, n) in motions {
         for _ in 0..n {
             head = step(head, dir);
             let mut prev = head;
@@ -85,13 +101,22 @@ U 20"#;
 
     #[test]
     fn example_1() {
-        assert_eq!(r"13", part_one(EXAMPLE_1).to_string());
-        assert_eq!(r"1", part_two(EXAMPLE_1).to_string());
+        let motions = parse(EXAMPLE_1).unwrap();
+        assert_eq!(r"13", part_one(&motions).to_string());
+        assert_eq!(r"1", part_two(&motions).to_string());
     }
 
     #[test]
     fn example_2() {
-        assert_eq!(r"36", part_two(EXAMPLE_2).to_string());
+        assert_eq!(r"36", part_two(&parse(EXAMPLE_2).unwrap()).to_string());
+    }
+
+    #[test]
+    fn bad_motions() {
+        let err = parse("R 4\nX 2").unwrap_err();
+        assert_eq!("Bad motion 'X 2' on line 2", err.to_string());
+        assert!(parse("R 4\n\u{e9}").is_err());
+        assert!(parse("U four").is_err());
     }
 
     #[test]
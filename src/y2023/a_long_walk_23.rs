@@ -1,8 +1,9 @@
+use crate::batch::solver_threads;
+use crate::graph::contract::{contract, Contracted, Keep};
+use crate::graph::longest::Longest;
 use crate::viz::graphviz::write_and_render;
 use crate::Part;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::fmt::Debug;
-use std::hash::Hash;
 use std::io::Write;
 use std::sync::mpsc::Sender;
 
@@ -15,88 +16,20 @@ type Pt = (usize, usize);
 
 fn part_one(input: &str) -> usize {
     let (start, goal, graph) = parse(input);
-    let mut forks = Vec::new();
-    forks.push(goal);
-    let mut queue = VecDeque::from([(start.0, 1)]);
-    let mut visited = HashSet::from([start]);
-    while let Some(curr) = queue.pop_front() {
-        if !visited.insert(curr) {
-            continue;
-        }
-        let (x, y) = curr;
-        if [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
-            .iter()
-            .filter(|p| graph.contains_key(p))
-            .take(3)
-            .count()
-            > 2
-        {
-            forks.push(curr);
-        }
-        for n in graph.get(&curr).unwrap() {
-            queue.push_back(*n);
-        }
-    }
-    let targets: HashSet<_> = forks.into_iter().collect();
-    let mut mini: HashMap<_, Vec<(Pt, usize)>> = HashMap::new();
-    let mut queue: VecDeque<_> = targets.iter().map(|&t| (t, t, 0)).collect();
-    queue.push_front((start, start, 0));
-    let mut visited = HashSet::new();
-    while let Some((start, curr, dist)) = queue.pop_front() {
-        if targets.contains(&curr) {
-            if dist > 0 {
-                mini.entry(start).or_default().push((curr, dist));
-            }
-            for n in graph.get(&curr).unwrap() {
-                queue.push_back((curr, *n, 1));
-            }
-        } else {
-            if !visited.insert(curr) {
-                continue;
-            }
-            for n in graph.get(&curr).unwrap() {
-                queue.push_back((start, *n, dist + 1));
-            }
-        }
-    }
-    // crate::viz::graphviz::render_weighted(&mini);
-    Dfs::longest(&mini, start, goal)
-}
-
-struct Dfs<'a, N> {
-    graph: &'a HashMap<N, Vec<(N, usize)>>,
-    goal: N,
-    visited: HashSet<N>,
+    let mini = contract(&graph, Keep::Forks, false);
+    // crate::viz::graphviz::render_dot(&petgraph::dot::Dot::new(&mini.graph));
+    longest(&mini, start, goal)
 }
 
-impl<'a, N> Dfs<'a, N>
-where
-    N: Copy + Eq + Hash + Debug,
-{
-    fn longest(graph: &'a HashMap<N, Vec<(N, usize)>>, start: N, goal: N) -> usize {
-        Dfs {
-            graph,
-            goal,
-            visited: HashSet::new(),
-        }
-        .dfs(start)
-        .expect("Failed to find any path?!")
-    }
-
-    fn dfs(&mut self, start: N) -> Option<usize> {
-        if start == self.goal {
-            return Some(0);
-        }
-        if !self.visited.insert(start) {
-            return None;
-        }
-        let longest = self.graph[&start]
-            .iter()
-            .filter_map(|&(e, d)| self.dfs(e).map(|v| d + v))
-            .max();
-        self.visited.remove(&start);
-        longest
-    }
+/// The longest path through the contracted graph, searched in parallel unless
+/// other solvers already are.
+fn longest(mini: &Contracted<Pt>, start: Pt, goal: Pt) -> usize {
+    Longest::from_graph(&mini.graph, mini.index[&start], mini.index[&goal], |c| {
+        c.len
+    })
+    .threads(solver_threads())
+    .solve()
+    .expect("Failed to find any path?!")
 }
 
 fn parse(input: &str) -> (Pt, Pt, HashMap<Pt, Vec<Pt>>) {
@@ -174,49 +107,10 @@ fn part_two(input: &str) -> usize {
         }
     }
     // un_render_unweighted(&graph);
-    let mini = contract(&graph, start, goal);
-    // crate::viz::graphviz::render_weighted(&mini);
-    Dfs::longest(&mini, start, goal)
-}
-
-fn contract(
-    graph: &HashMap<Pt, Vec<Pt>>,
-    start: (usize, usize),
-    goal: (usize, usize),
-) -> HashMap<Pt, Vec<(Pt, usize)>> {
-    let mut of_interest: HashSet<_> = graph
-        .iter()
-        .filter(|(_, es)| es.len() > 2)
-        .map(|(n, _)| *n)
-        .collect();
-    of_interest.insert(start);
-    of_interest.insert(goal);
-    let mut mini: HashMap<_, Vec<_>> = HashMap::new();
-    let mut queue = VecDeque::new();
-    let mut visited = HashSet::new();
-    for st in of_interest.iter() {
-        assert!(
-            queue.is_empty(),
-            "started {st:?} processing, but queue wasn't drained?!"
-        );
-        queue.push_back((st, 0));
-        visited.clear();
-        while let Some((curr, dist)) = queue.pop_front() {
-            if !visited.insert(curr) {
-                continue;
-            }
-            if dist > 0 && of_interest.contains(curr) {
-                mini.entry(*st).or_default().push((*curr, dist))
-            } else {
-                for n in graph.get(curr).unwrap() {
-                    queue.push_back((n, dist + 1))
-                }
-            }
-        }
-    }
-    // un_render_weighted(&mini);
-    // render_weighted(&mini);
-    mini
+    let keep = Keep::ForksAnd(HashSet::from([start, goal]));
+    let mini = contract(&graph, keep, false);
+    // crate::viz::graphviz::render_dot(&petgraph::dot::Dot::new(&mini.graph));
+    longest(&mini, start, goal)
 }
 
 #[allow(dead_code)]
@@ -242,29 +136,6 @@ fn un_render_unweighted(graph: &HashMap<Pt, Vec<Pt>>) {
     })
 }
 
-#[allow(dead_code)]
-fn un_render_weighted(graph: &HashMap<Pt, Vec<(Pt, usize)>>) {
-    write_and_render(|f| {
-        let mut index = HashMap::new();
-        let mut nodes: Vec<_> = graph.iter().map(|(n, _)| n).collect();
-        nodes.sort();
-        writeln!(f, "graph {{")?;
-        for (i, &n) in nodes.iter().enumerate() {
-            index.insert(n, i);
-            writeln!(f, "{i} [label=\"{n:?}\"]")?;
-        }
-        for (n, es) in graph {
-            let i = index.get(n).unwrap();
-            for (e, w) in es {
-                if n < e {
-                    writeln!(f, "{i} -- {} [label=\"{w:?}\"]", index.get(e).unwrap())?;
-                }
-            }
-        }
-        writeln!(f, "}}")
-    })
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::geom2d::{Dir8, Point2};
 use crate::Part;
 use std::sync::mpsc::Sender;
 
@@ -8,59 +9,24 @@ pub fn do_solve(input: &str, tx: Sender<Part>) {
 
 fn part_one(input: &str) -> usize {
     let grid: Vec<Vec<_>> = input.lines().map(|l| l.chars().collect()).collect();
-    let height = grid.len();
-    let width = grid[0].len();
+    let at = |p: Point2<isize>| {
+        let r = usize::try_from(p.y).ok()?;
+        let c = usize::try_from(p.x).ok()?;
+        grid.get(r)?.get(c).copied()
+    };
     let mut count = 0;
-    for r in 0..height {
-        for c in 0..width {
-            if grid[r][c] == 'X' {
-                let can_n = r >= 3;
-                let can_s = r < height - 3;
-                let can_w = c >= 3;
-                let can_e = c < width - 3;
-                if can_n {
-                    if grid[r - 1][c] == 'M' && grid[r - 2][c] == 'A' && grid[r - 3][c] == 'S' {
-                        count += 1;
-                    }
-                    if can_w
-                        && grid[r - 1][c - 1] == 'M'
-                        && grid[r - 2][c - 2] == 'A'
-                        && grid[r - 3][c - 3] == 'S'
-                    {
-                        count += 1;
-                    }
-                    if can_e
-                        && grid[r - 1][c + 1] == 'M'
-                        && grid[r - 2][c + 2] == 'A'
-                        && grid[r - 3][c + 3] == 'S'
-                    {
-                        count += 1;
-                    }
-                }
-                if can_e && grid[r][c + 1] == 'M' && grid[r][c + 2] == 'A' && grid[r][c + 3] == 'S'
-                {
-                    count += 1;
-                }
-                if can_s {
-                    if grid[r + 1][c] == 'M' && grid[r + 2][c] == 'A' && grid[r + 3][c] == 'S' {
-                        count += 1;
-                    }
-                    if can_w
-                        && grid[r + 1][c - 1] == 'M'
-                        && grid[r + 2][c - 2] == 'A'
-                        && grid[r + 3][c - 3] == 'S'
-                    {
-                        count += 1;
-                    }
-                    if can_e
-                        && grid[r + 1][c + 1] == 'M'
-                        && grid[r + 2][c + 2] == 'A'
-                        && grid[r + 3][c + 3] == 'S'
-                    {
-                        count += 1;
-                    }
-                }
-                if can_w && grid[r][c - 1] == 'M' && grid[r][c - 2] == 'A' && grid[r][c - 3] == 'S'
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &ch) in row.iter().enumerate() {
+            if ch != 'X' {
+                continue;
+            }
+            let start = Point2::new(c as isize, r as isize);
+            for d in Dir8::ALL {
+                let step: Point2<isize> = d.into();
+                if "MAS"
+                    .chars()
+                    .enumerate()
+                    .all(|(i, x)| at(start + step * (i as isize + 1)) == Some(x))
                 {
                     count += 1;
                 }
@@ -1,3 +1,4 @@
+use crate::grid::{Grid, Pt};
 use crate::Part;
 use std::collections::HashMap;
 use std::sync::mpsc::Sender;
@@ -11,45 +12,26 @@ pub fn do_solve(input: &str, tx: Sender<Part>) {
     tx.send(Part::B(part_two(&stats).to_string())).unwrap();
 }
 
-type Grid = Vec<Vec<char>>;
-
-fn parse(input: &str) -> Grid {
-    input.lines().map(|line| line.chars().collect()).collect()
+fn parse(input: &str) -> Grid<char> {
+    input.parse().unwrap()
 }
 
-type Pt = (usize, usize);
-
 type Stats = HashMap<Pt, HashMap<Pt, usize>>;
 
-fn trailhead_stats(grid: &Vec<Vec<char>>) -> Stats {
-    let max_x = grid[0].len() - 1;
-    let max_y = grid.len() - 1;
+fn trailhead_stats(grid: &Grid<char>) -> Stats {
     let mut result = HashMap::new();
-    for (y, row) in grid.iter().enumerate() {
-        for (x, c) in row.iter().enumerate() {
-            if *c == '0' {
-                let mut curr = HashMap::from([((x, y), 1)]);
-                for tgt in '1'..='9' {
-                    let mut next = HashMap::new();
-                    for ((x, y), n) in curr {
-                        if x > 0 && grid[y][x - 1] == tgt {
-                            *next.entry((x - 1, y)).or_default() += n;
-                        }
-                        if x < max_x && grid[y][x + 1] == tgt {
-                            *next.entry((x + 1, y)).or_default() += n;
-                        }
-                        if y > 0 && grid[y - 1][x] == tgt {
-                            *next.entry((x, y - 1)).or_default() += n;
-                        }
-                        if y < max_y && grid[y + 1][x] == tgt {
-                            *next.entry((x, y + 1)).or_default() += n;
-                        }
-                    }
-                    curr = next;
+    for head in grid.find_all(|&c| c == '0') {
+        let mut curr = HashMap::from([(head, 1)]);
+        for tgt in '1'..='9' {
+            let mut next = HashMap::new();
+            for (p, n) in curr {
+                for (_, q) in grid.neighbors(p).filter(|&(_, q)| grid[q] == tgt) {
+                    *next.entry(q).or_default() += n;
                 }
-                result.insert((x, y), curr);
             }
+            curr = next;
         }
+        result.insert(head, curr);
     }
     result
 }
@@ -160,13 +160,10 @@ fn shortest_path(code: &str, dir_bots: usize) -> usize {
             let mut gen = path;
             for _ in 0..dir_bots {
                 // println!("  for {gen:#?}, after {b} dir bots: ");
-                let mut next = Histogram::new();
-                for (pmv, n) in gen {
-                    for (nmv, m) in DIRECTIONAL.the_one_true_path(&pmv) {
-                        next.add(nmv, n * m);
-                    }
-                }
-                gen = next;
+                gen = gen
+                    .into_iter()
+                    .map(|(pmv, n)| DIRECTIONAL.the_one_true_path(&pmv) * n)
+                    .sum();
             }
             best = best.min(gen.total())
         }
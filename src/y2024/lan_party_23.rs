@@ -1,8 +1,8 @@
-use crate::hist::Histogram;
+use crate::graph::undirected::{max_clique, triangles};
 use crate::Part;
 use petgraph::graph::UnGraph;
 use petgraph::{Graph, Undirected};
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::mpsc::Sender;
 
 pub fn do_solve(input: &str, tx: Sender<Part>) {
@@ -25,62 +25,16 @@ fn build_network(input: &str) -> Graph<&str, (), Undirected> {
 }
 
 fn part_one(net: &Graph<&str, (), Undirected>) -> usize {
-    let mut triples = HashSet::new();
-    for curr in net.node_indices() {
-        for a in net.neighbors(curr) {
-            for b in net.neighbors(a) {
-                for c in net.neighbors(b) {
-                    if c == curr {
-                        let mut t = vec![a, b, c];
-                        t.sort();
-                        triples.insert(t);
-                        break;
-                    }
-                }
-            }
-        }
-    }
-    triples
+    triangles(net)
         .into_iter()
-        .filter(|t| {
-            t.iter()
-                .any(|c| net.node_weight(*c).unwrap().starts_with('t'))
-        })
+        .filter(|t| t.iter().any(|c| c.starts_with('t')))
         .count()
 }
 
 fn part_two(net: &Graph<&str, (), Undirected>) -> String {
-    for curr in net.node_indices() {
-        let mut hist = Histogram::new();
-        for a in net.neighbors(curr) {
-            hist.increment(a);
-            for b in net.neighbors(a) {
-                hist.increment(b)
-            }
-        }
-        let mut sets: HashMap<usize, Vec<_>> = HashMap::new();
-        for (nx, n) in hist {
-            sets.entry(n)
-                .or_default()
-                .push(net.node_weight(nx).unwrap());
-        }
-        for (n, lbls) in sets {
-            if lbls.len() == n {
-                let mut to_sort: Vec<_> = lbls.iter().map(|l| **l).collect();
-                to_sort.push(net.node_weight(curr).unwrap());
-                to_sort.sort();
-                let mut buf = String::new();
-                for l in to_sort {
-                    if !buf.is_empty() {
-                        buf.push(',');
-                    }
-                    buf.push_str(l);
-                }
-                return buf;
-            }
-        }
-    }
-    panic!("didn't find any LAN party?!")
+    let mut party = max_clique(net);
+    party.sort();
+    party.join(",")
 }
 
 #[cfg(test)]
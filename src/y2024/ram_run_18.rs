@@ -1,5 +1,6 @@
+use crate::search::bfs_to;
 use crate::Part;
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
 use std::sync::mpsc::Sender;
 
 pub fn do_solve(input: &str, tx: Sender<Part>) {
@@ -30,32 +31,17 @@ fn part_one_parameterized(bytes: &Vec<Pt>, max: usize, count: usize) -> usize {
 
 fn either_part(corruption: &HashSet<&Pt>, max: usize) -> Option<usize> {
     let goal = (max, max);
-    let mut queue = VecDeque::new();
-    queue.push_back(((0, 0), 0));
-    let mut visited = HashSet::new();
-    while let Some((p, steps)) = queue.pop_front() {
-        if corruption.contains(&p) || !visited.insert(p) {
-            continue;
-        }
-        if p == goal {
-            return Some(steps);
-        }
-        let (x, y) = p;
-        let steps = steps + 1;
-        if x > 0 {
-            queue.push_back(((x - 1, y), steps))
-        }
-        if y > 0 {
-            queue.push_back(((x, y - 1), steps))
-        }
-        if x < max {
-            queue.push_back(((x + 1, y), steps))
-        }
-        if y < max {
-            queue.push_back(((x, y + 1), steps))
-        }
-    }
-    None
+    let steps = |&(x, y): &Pt| {
+        [
+            (x.wrapping_sub(1), y),
+            (x, y.wrapping_sub(1)),
+            (x + 1, y),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter(|p| p.0 <= max && p.1 <= max && !corruption.contains(p))
+    };
+    bfs_to((0, 0), steps, |&p| p == goal).cost()
 }
 
 fn part_two(bytes: &Vec<Pt>) -> String {
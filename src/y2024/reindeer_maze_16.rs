@@ -1,3 +1,4 @@
+use crate::cons::{union, Cons};
 use crate::geom2d::Dir;
 use crate::geom2d::Dir::*;
 use crate::Part;
@@ -5,8 +6,6 @@ use petgraph::prelude::{EdgeRef, NodeIndex};
 use petgraph::Graph;
 use std::collections::hash_map::Entry::Occupied;
 use std::collections::{HashMap, VecDeque};
-use std::ops::Deref;
-use std::rc::Rc;
 use std::sync::mpsc::Sender;
 
 pub fn do_solve(input: &str, tx: Sender<Part>) {
@@ -73,27 +72,12 @@ impl From<&str> for Maze {
     }
 }
 
-enum Cons<T> {
-    Nil,
-    Cons(T, Rc<Cons<T>>),
-}
-
-impl<T> Cons<T> {
-    fn of(t: T) -> Self {
-        Cons::Cons(t, Rc::new(Cons::Nil))
-    }
-
-    fn push(head: T, tail: Rc<Cons<T>>) -> Self {
-        Cons::Cons(head, tail.clone())
-    }
-}
-
 type Coords = (NodeIndex, Dir);
-type State = (NodeIndex, Dir, u32, Rc<Cons<NodeIndex>>);
+type State = (NodeIndex, Dir, u32, Cons<NodeIndex>);
 
 fn both_parts(maze: &Maze) -> (u32, u32) {
     let mut queue: VecDeque<State> = VecDeque::new();
-    queue.push_back((maze.start, East, 0, Rc::new(Cons::of(maze.start))));
+    queue.push_back((maze.start, East, 0, Cons::of(maze.start)));
     let mut visited: HashMap<Coords, u32> = HashMap::new();
     let mut best = u32::MAX;
     let mut good_seats = Vec::new();
@@ -130,47 +114,11 @@ fn both_parts(maze: &Maze) -> (u32, u32) {
             }
             if c <= best {
                 let ox = ex.target();
-                queue.push_back((ox, d, c, Rc::new(Cons::push(ox, path.clone()))))
-            }
-        }
-    }
-    let on_path = flatten(good_seats);
-    (best, count_distinct(on_path))
-}
-
-fn flatten(good_seats: Vec<Rc<Cons<NodeIndex>>>) -> Vec<NodeIndex> {
-    let mut on_path = Vec::new();
-    for mut seat in good_seats {
-        loop {
-            match seat.deref() {
-                Cons::Nil => break,
-                Cons::Cons(nx, l) => {
-                    on_path.push(*nx);
-                    seat = l.to_owned();
-                }
+                queue.push_back((ox, d, c, path.push(ox)))
             }
         }
     }
-    on_path
-}
-
-fn count_distinct<T>(mut on_path: Vec<T>) -> u32
-where
-    T: Copy + Eq + Ord,
-{
-    if on_path.is_empty() {
-        return 0;
-    }
-    on_path.sort();
-    let mut prev = on_path[0];
-    let mut count = 1;
-    for curr in on_path {
-        if curr != prev {
-            count += 1
-        }
-        prev = curr;
-    }
-    count
+    (best, union(&good_seats).len() as u32)
 }
 
 #[cfg(test)]
@@ -1,8 +1,7 @@
 use crate::block_print::BLOCK;
+use crate::geom2d::Point2;
 use crate::Part;
 use std::collections::{HashMap, HashSet};
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 use std::str::FromStr;
 use std::sync::mpsc::Sender;
 
@@ -13,61 +12,7 @@ pub fn do_solve(input: &str, tx: Sender<Part>) {
     tx.send(Part::B(part_two(&model).to_string())).unwrap();
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct Pt {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Display for Pt {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
-impl Pt {
-    pub(crate) fn new(x: isize, y: isize) -> Pt {
-        Pt { x, y }
-    }
-}
-
-impl Add for Pt {
-    type Output = Pt;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Pt::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl AddAssign for Pt {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x = self.x + rhs.x;
-        self.y = self.y + rhs.y;
-    }
-}
-
-impl Sub for Pt {
-    type Output = Pt;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Pt::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl SubAssign for Pt {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x = self.x - rhs.x;
-        self.y = self.y - rhs.y;
-    }
-}
-
-impl Neg for Pt {
-    type Output = Pt;
-
-    fn neg(self) -> Self::Output {
-        Pt::new(-self.x, -self.y)
-    }
-}
+type Pt = Point2<isize>;
 
 struct Model {
     antennas: HashMap<char, Vec<Pt>>,
@@ -105,10 +50,7 @@ impl FromStr for Model {
 
 impl Model {
     fn is_within_area(&self, p: &Pt) -> bool {
-        if p.x < 0 || p.y < 0 {
-            return false;
-        }
-        p.x <= self.bounds.x && p.y <= self.bounds.y
+        p.is_within(Pt::default(), self.bounds)
     }
 
     #[allow(dead_code)]
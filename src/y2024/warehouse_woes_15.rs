@@ -1,4 +1,4 @@
-use crate::geom2d::{step, Dir};
+use crate::geom2d::{parse_moves, step, Dir, ParseDirError};
 use crate::Part;
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter, Write};
@@ -13,6 +13,18 @@ pub fn do_solve(input: &str, tx: Sender<Part>) {
 
 type Pt = (usize, usize);
 
+/// Splits the map's lines from the moves, at the first blank line.
+fn split_input(input: &str) -> Result<(Vec<&str>, Vec<Dir>), ParseDirError> {
+    let mut lines = input.lines();
+    let map = lines
+        .by_ref()
+        .take_while(|l| !l.trim().is_empty())
+        .collect();
+    // parse the moves as one block, so errors give their place in it
+    let moves = parse_moves(&lines.collect::<Vec<_>>().join("\n"))?;
+    Ok((map, moves))
+}
+
 #[derive(Debug)]
 struct Warehouse {
     width: usize,
@@ -24,40 +36,31 @@ struct Warehouse {
 }
 
 impl FromStr for Warehouse {
-    type Err = ();
+    type Err = ParseDirError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut floor = HashSet::new();
         let mut boxes = HashSet::new();
-        let mut instructions = Vec::new();
         let mut bot = None;
         let mut width = None;
         let mut height = None;
-        let mut before_break = true;
-        for (y, line) in input.lines().enumerate() {
-            if line == "" {
-                before_break = false;
-                continue;
-            }
-            if before_break {
-                height = Some(y + 1);
-                let line: Vec<_> = line.chars().collect();
-                width = Some(line.len());
-                for (x, &c) in line.iter().enumerate() {
-                    if c == '@' {
-                        if let Some(p) = bot {
-                            panic!("Found two bots?! {p:?} and ({x}, {y})?!")
-                        }
-                        bot = Some((x, y));
-                    } else if c == 'O' {
-                        boxes.insert((x, y));
-                    }
-                    if c != '#' {
-                        floor.insert((x, y));
+        let (map, instructions) = split_input(input)?;
+        for (y, line) in map.iter().enumerate() {
+            height = Some(y + 1);
+            let line: Vec<_> = line.chars().collect();
+            width = Some(line.len());
+            for (x, &c) in line.iter().enumerate() {
+                if c == '@' {
+                    if let Some(p) = bot {
+                        panic!("Found two bots?! {p:?} and ({x}, {y})?!")
                     }
+                    bot = Some((x, y));
+                } else if c == 'O' {
+                    boxes.insert((x, y));
+                }
+                if c != '#' {
+                    floor.insert((x, y));
                 }
-            } else {
-                instructions.extend(line.chars().map(Dir::from))
             }
         }
         Ok(Warehouse {
@@ -130,44 +133,35 @@ struct BiggerWarehouse {
 }
 
 impl FromStr for BiggerWarehouse {
-    type Err = ();
+    type Err = ParseDirError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut floor = HashSet::new();
         let mut lefts = HashSet::new();
         let mut rights = HashSet::new();
-        let mut instructions = Vec::new();
         let mut bot = None;
         let mut width = None;
         let mut height = None;
-        let mut before_break = true;
-        for (y, line) in input.lines().enumerate() {
-            if line == "" {
-                before_break = false;
-                continue;
-            }
-            if before_break {
-                height = Some(y + 1);
-                let line: Vec<_> = line.chars().collect();
-                width = Some(line.len() * 2);
-                for (x, &c) in line.iter().enumerate() {
-                    let x = x * 2;
-                    if c == '@' {
-                        if let Some(p) = bot {
-                            panic!("Found two bots?! {p:?} and ({x}, {y})?!")
-                        }
-                        bot = Some((x, y));
-                    } else if c == 'O' {
-                        lefts.insert((x, y));
-                        rights.insert((x + 1, y));
-                    }
-                    if c != '#' {
-                        floor.insert((x, y));
-                        floor.insert((x + 1, y));
+        let (map, instructions) = split_input(input)?;
+        for (y, line) in map.iter().enumerate() {
+            height = Some(y + 1);
+            let line: Vec<_> = line.chars().collect();
+            width = Some(line.len() * 2);
+            for (x, &c) in line.iter().enumerate() {
+                let x = x * 2;
+                if c == '@' {
+                    if let Some(p) = bot {
+                        panic!("Found two bots?! {p:?} and ({x}, {y})?!")
                     }
+                    bot = Some((x, y));
+                } else if c == 'O' {
+                    lefts.insert((x, y));
+                    rights.insert((x + 1, y));
+                }
+                if c != '#' {
+                    floor.insert((x, y));
+                    floor.insert((x + 1, y));
                 }
-            } else {
-                instructions.extend(line.chars().map(Dir::from))
             }
         }
         Ok(BiggerWarehouse {
@@ -343,6 +337,23 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
         assert_eq!(EXAMPLE_1_WIDE, wh.to_string());
     }
 
+    #[test]
+    fn bad_move() {
+        let input = "#####\n#@.O#\n#####\n\n<>\n^x";
+        let err = input.parse::<Warehouse>().unwrap_err();
+        assert_eq!(ParseDirError::BadChar { c: 'x', at: 4 }, err);
+    }
+
+    #[test]
+    fn crlf() {
+        let input = EXAMPLE_2.replace('\n', "\r\n");
+        let expected: Warehouse = EXAMPLE_2.parse().unwrap();
+        let warehouse: Warehouse = input.parse().unwrap();
+        assert_eq!(expected.instructions, warehouse.instructions);
+        assert_eq!(expected.to_string(), warehouse.to_string());
+        assert_eq!(r"2028", part_one(&input).to_string());
+    }
+
     #[test]
     fn example_2() {
         assert_eq!(r"2028", part_one(EXAMPLE_2).to_string());
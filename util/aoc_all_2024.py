@@ -1,5 +1,4 @@
 import os
-import re
 import subprocess
 
 # noinspection PyUnresolvedReferences
@@ -7,6 +6,7 @@ from aocd.models import Puzzle
 
 from .lib import (
     compute_done,
+    parse_events,
     puzzle_name,
 )
 
@@ -19,7 +19,8 @@ def solve(year, day, data):
     puzzle = Puzzle(year=year, day=day)
     env = {}
     env.update(os.environ)
-    env["BEB_EXTERNAL_RUN"] = "1"
+    env["BEB_JSON_EVENTS"] = "1"
+    env["BEB_SUBMIT"] = "0"  # aocd is doing the verifying
     proc = subprocess.run(
         [
             "cargo",
@@ -41,19 +42,27 @@ def solve(year, day, data):
     part_a = None
     part_b = None
     part_t = None
-    for p, ans in re.findall(r"\[__AOCD_VERIFY_([ABT])__\[([^]]+)]]", str(proc.stdout)):
-        if p == "A":
-            if part_a is None:
-                part_a = ans
-            elif part_a != ans:
-                raise Exception(f"Multiple answers for part A?! '{part_a}' and {ans}")
-        elif p == "B":
-            if part_b is None:
+    for event in parse_events(proc.stdout):
+        kind = event["kind"]
+        if kind == "both":
+            answers = [("a", event["answer"][0]), ("b", event["answer"][1])]
+        elif kind in ("a", "b"):
+            answers = [(kind, event["answer"])]
+        elif kind == "join":
+            if part_t is not None:
+                raise Exception(f"Multiple reported times?! '{part_t}' and {event['nanos']}")
+            part_t = event["nanos"]
+            continue
+        else:
+            continue
+        for p, ans in answers:
+            if p == "a":
+                if part_a is None:
+                    part_a = ans
+                elif part_a != ans:
+                    raise Exception(f"Multiple answers for part A?! '{part_a}' and {ans}")
+            elif part_b is None:
                 part_b = ans
             elif part_b != ans:
                 raise Exception(f"Multiple answers for part B?! '{part_b}' and {ans}")
-        elif part_t is None:
-            part_t = int(ans)
-        else:
-            raise Exception(f"Multiple reported times?! '{part_t}' and {ans}")
     return part_a, part_b, part_t
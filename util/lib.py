@@ -1,3 +1,4 @@
+import json
 import os
 import re
 import subprocess
@@ -40,3 +41,18 @@ def puzzle_name(puzzle):
     if not name[0].isalpha():
         name = "_" + name
     return name
+
+
+def parse_events(stdout):
+    """I parse the JSON event objects a solver prints when BEB_JSON_EVENTS is
+    set, skipping any other lines it may have printed along the way.
+    """
+    for line in stdout.splitlines():
+        if not line.startswith("{"):
+            continue
+        try:
+            event = json.loads(line)
+        except ValueError:
+            continue
+        if isinstance(event, dict) and "kind" in event:
+            yield event